@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::{parse_number, ParseError};
+use crate::runner::Part;
+
+/// Default file name of the recorded answers, inside the inputs directory.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Known-good answers for real inputs, keyed by day, part and input name.
+///
+/// Stored one answer per line as `<day> <part> <input> <answer>`, e.g. `1 a 1 1341714`. Blank
+/// lines and lines starting with `#` are ignored.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part, String), String>,
+}
+
+impl Answers {
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut answers = BTreeMap::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let mut next = |expected| {
+                fields
+                    .next()
+                    .ok_or_else(|| ParseError::at(content, &line[line.len()..], expected))
+            };
+            let day = parse_number(content, next("a day")?)?;
+            let part_field = next("a part")?;
+            let part = part_field
+                .parse()
+                .map_err(|_| ParseError::at(content, part_field, "`a` or `b`"))?;
+            let input = next("an input name")?;
+            let answer = next("an answer")?;
+            if let Some(extra) = fields.next() {
+                return Err(ParseError::at(content, extra, "end of line"));
+            }
+            answers.insert((day, part, input.to_string()), answer.to_string());
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, input: &str, answer: &str) {
+        self.answers
+            .insert((day, part, input.to_string()), answer.to_string());
+    }
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day part input answer")?;
+        for ((day, part, input), answer) in &self.answers {
+            writeln!(f, "{day} {part} {input} {answer}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::error::{parse_number, ParseError};
+use crate::runner::{Part, Solver};
+
+/// A timed phase of solving a day.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Solve(part) => write!(f, "{part}"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            _ => s.parse().map(Stage::Solve),
+        }
+    }
+}
+
+/// Summary of repeated timings of one stage.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub median: Duration,
+    /// Standard deviation, i.e. the square root of the variance.
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / samples.len() as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+        Stats {
+            runs: samples.len(),
+            median,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Parse and solve `input` `runs` times after one warm-up run, timing each stage separately.
+pub fn measure(
+    solver: &Solver,
+    input: &str,
+    runs: usize,
+) -> Result<Vec<(Stage, Stats)>, ParseError> {
+    solver.run(input, &Part::BOTH)?;
+    let mut samples: BTreeMap<Stage, Vec<Duration>> = BTreeMap::new();
+    for _ in 0..runs {
+        let result = solver.run(input, &Part::BOTH)?;
+        samples
+            .entry(Stage::Parse)
+            .or_default()
+            .push(result.parse_time);
+        for part in result.parts {
+            samples
+                .entry(Stage::Solve(part.part))
+                .or_default()
+                .push(part.elapsed);
+        }
+    }
+    Ok(samples
+        .into_iter()
+        .map(|(stage, samples)| (stage, Stats::from_samples(&samples)))
+        .collect())
+}
+
+/// Median timings saved from an earlier benchmark, to compare new runs against.
+///
+/// Stored one stage per line as `<day> <stage> <median nanoseconds>`, e.g. `16 b 33330000`.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u8, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut medians = BTreeMap::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let mut next = |expected| {
+                fields
+                    .next()
+                    .ok_or_else(|| ParseError::at(content, &line[line.len()..], expected))
+            };
+            let day = parse_number(content, next("a day")?)?;
+            let stage_field = next("a stage")?;
+            let stage = stage_field
+                .parse()
+                .map_err(|_| ParseError::at(content, stage_field, "`parse`, `a` or `b`"))?;
+            let nanos = parse_number(content, next("a duration in nanoseconds")?)?;
+            if let Some(extra) = fields.next() {
+                return Err(ParseError::at(content, extra, "end of line"));
+            }
+            medians.insert((day, stage), Duration::from_nanos(nanos));
+        }
+        Ok(Baseline { medians })
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+
+    pub fn insert(&mut self, day: u8, stage: Stage, median: Duration) {
+        self.medians.insert((day, stage), median);
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# day stage median_ns")?;
+        for ((day, stage), median) in &self.medians {
+            writeln!(f, "{day} {stage} {}", median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+/// Relative change of `median` compared to `baseline`, e.g. `0.25` for 25% slower.
+pub fn relative_change(median: Duration, baseline: Duration) -> f64 {
+    median.as_secs_f64() / baseline.as_secs_f64() - 1.0
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use advent_of_code_2024::bench::{self, Baseline, Stage};
+use advent_of_code_2024::input::InputSource;
+use advent_of_code_2024::runner::Solver;
+
+use crate::run;
+
+pub struct Options {
+    pub runs: usize,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+    /// Slowdown in percent above which a stage counts as a regression.
+    pub threshold: f64,
+}
+
+fn read_baseline(path: &Path) -> Result<Baseline, String> {
+    let content =
+        fs::read_to_string(path).map_err(|error| format!("{}: {error}", path.display()))?;
+    Baseline::parse(&content).map_err(|error| error.with_file(path).to_string())
+}
+
+fn label(day: u8, stage: Stage) -> String {
+    match stage {
+        Stage::Parse => format!("Day {day} parse"),
+        Stage::Solve(part) => format!("Day {day}{part}"),
+    }
+}
+
+pub fn bench(solvers: &[&Solver], source: &InputSource, options: &Options) -> ExitCode {
+    let baseline = match options.baseline.as_deref().map(read_baseline).transpose() {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("Problem reading the baseline: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut regressions = 0;
+    let mut measured = Baseline::default();
+    for solver in solvers {
+        let input = match source.read(solver.day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("Problem reading the input for day {}: {error}", solver.day);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        // A panicking day counts as failed rather than stopping the others
+        let measured_day = panic::catch_unwind(AssertUnwindSafe(|| {
+            bench::measure(solver, &input, options.runs)
+        }));
+        let stages = match measured_day {
+            Ok(Ok(stages)) => stages,
+            Err(payload) => {
+                let message = run::panic_message(&*payload);
+                eprintln!("Day {} panicked: {message}", solver.day);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+            Ok(Err(error)) => {
+                let error = error.with_file(source.path(solver.day).unwrap_or_default());
+                eprintln!("Problem parsing the input for day {}: {error}", solver.day);
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        for (stage, stats) in stages {
+            measured.insert(solver.day, stage, stats.median);
+            let mut line = format!(
+                "{}: {:.2?} ± {:.2?}",
+                label(solver.day, stage),
+                stats.median,
+                stats.std_dev
+            );
+            if let Some(base) = baseline
+                .as_ref()
+                .and_then(|baseline| baseline.get(solver.day, stage))
+            {
+                let change = bench::relative_change(stats.median, base) * 100.0;
+                line += &format!(" (baseline {base:.2?}, {change:+.1}%)");
+                if change > options.threshold {
+                    line += " REGRESSION";
+                    regressions += 1;
+                }
+            }
+            println!("{line}");
+        }
+    }
+
+    if baseline.is_some() {
+        println!(
+            "{regressions} regression(s) over {}% in {} runs",
+            options.threshold, options.runs
+        );
+        if regressions > 0 {
+            status = ExitCode::FAILURE;
+        }
+    }
+    if let Some(path) = &options.save_baseline {
+        if let Err(error) = fs::write(path, measured.to_string()) {
+            eprintln!("Problem writing {}: {error}", path.display());
+            return ExitCode::FAILURE;
+        }
+        println!("Saved the baseline to {}", path.display());
+    }
+    status
+}
@@ -0,0 +1,160 @@
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+use advent_of_code_2024::days::day17::debugger::Debugger;
+use advent_of_code_2024::days::day17::invert;
+use advent_of_code_2024::days::day17::{analysis, asm};
+use advent_of_code_2024::days::Day17;
+use advent_of_code_2024::input::InputSource;
+use advent_of_code_2024::Solution;
+use clap::Subcommand;
+use itertools::Itertools;
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Print a day 17 input's program as assembly
+    Disasm {
+        /// Input file, or `-` to read stdin [default: the day 17 input]
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Print the control flow, output sources, shape and termination of a day 17 input's program
+    Analyse {
+        /// Input file, or `-` to read stdin [default: the day 17 input]
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Step through a day 17 input's program interactively
+    Debug {
+        /// Input file [default: the day 17 input]
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Find the lowest A that makes a day 17 input's program output a sequence
+    Invert {
+        /// Input file, or `-` to read stdin [default: the day 17 input]
+        #[arg(short, long)]
+        input: Option<String>,
+        /// Comma-separated digits to output [default: the program itself]
+        #[arg(short, long, value_delimiter = ',')]
+        target: Option<Vec<u8>>,
+    },
+    /// Turn assembly back into the comma-separated program of a day 17 input
+    Asm {
+        /// Assembly file, or `-` to read stdin
+        file: String,
+    },
+}
+
+fn read(source: &InputSource) -> Result<String, ExitCode> {
+    source.read(17).map_err(|error| {
+        eprintln!("Problem reading {error}");
+        ExitCode::FAILURE
+    })
+}
+
+fn load(source: &InputSource) -> Result<<Day17 as Solution>::Input, ExitCode> {
+    let input = read(source)?;
+    Day17::parse(&input).map_err(|error| {
+        let error = error.with_file(source.path(17).unwrap_or_default());
+        eprintln!("Problem parsing the input: {error}");
+        ExitCode::FAILURE
+    })
+}
+
+fn disasm(source: &InputSource) -> Result<(), ExitCode> {
+    let (memory, _, operations) = load(source)?;
+    println!("; Register A: {}", memory.a);
+    println!("; Register B: {}", memory.b);
+    println!("; Register C: {}", memory.c);
+    print!("{}", asm::disassemble(&operations));
+    Ok(())
+}
+
+fn analyse(source: &InputSource) -> Result<(), ExitCode> {
+    let (_, _, operations) = load(source)?;
+    println!("{}", analysis::analyse(&operations));
+    Ok(())
+}
+
+fn debug(source: &InputSource) -> Result<(), ExitCode> {
+    let (memory, program, _) = load(source)?;
+    let mut debugger = Debugger::new(memory, program);
+    println!("{}", debugger.location());
+    println!("Type `help` for the commands");
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("(day17) ");
+        io::stdout().flush().unwrap();
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            return Ok(());
+        };
+        match line.trim() {
+            "quit" | "q" => return Ok(()),
+            command => match debugger.execute(command) {
+                Ok(text) if text.is_empty() => {}
+                Ok(text) => println!("{text}"),
+                Err(error) => eprintln!("{error}"),
+            },
+        }
+    }
+}
+
+fn invert(source: &InputSource, target: Option<Vec<u8>>) -> Result<(), ExitCode> {
+    let (memory, program, _) = load(source)?;
+    let target = target.unwrap_or_else(|| program.clone());
+    match invert::lowest_a(memory, &program, &target) {
+        Ok(Some(a)) => println!("{a}"),
+        Ok(None) => println!("No value of A outputs {}", target.iter().join(",")),
+        Err(unsupported) => {
+            eprintln!("Can't invert the program: {unsupported}");
+            return Err(ExitCode::FAILURE);
+        }
+    }
+    Ok(())
+}
+
+fn asm(source: &InputSource) -> Result<(), ExitCode> {
+    let text = read(source)?;
+    let operations = asm::assemble(&text).map_err(|error| {
+        let path = source
+            .path(17)
+            .unwrap_or_else(|| Path::new("<stdin>").to_path_buf());
+        eprintln!("Problem assembling the program: {}", error.with_file(path));
+        ExitCode::FAILURE
+    })?;
+    println!("{}", asm::encode(&operations).iter().join(","));
+    Ok(())
+}
+
+pub fn day17(command: Command) -> ExitCode {
+    let result = match command {
+        Command::Disasm { input } => {
+            let source =
+                input.map_or_else(InputSource::from_env, |input| InputSource::from_arg(&input));
+            disasm(&source)
+        }
+        Command::Analyse { input } => {
+            let source =
+                input.map_or_else(InputSource::from_env, |input| InputSource::from_arg(&input));
+            analyse(&source)
+        }
+        Command::Debug { input } => {
+            let source =
+                input.map_or_else(InputSource::from_env, |input| InputSource::from_arg(&input));
+            debug(&source)
+        }
+        Command::Invert { input, target } => {
+            let source =
+                input.map_or_else(InputSource::from_env, |input| InputSource::from_arg(&input));
+            invert(&source, target)
+        }
+        Command::Asm { file } => asm(&InputSource::from_arg(&file)),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(status) => status,
+    }
+}
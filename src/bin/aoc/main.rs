@@ -0,0 +1,285 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use advent_of_code_2024::answers::ANSWERS_FILE;
+use advent_of_code_2024::generate;
+use advent_of_code_2024::input::{self, InputSource};
+use advent_of_code_2024::parallel;
+use advent_of_code_2024::runner::{self, Part, Solver};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+
+mod bench;
+mod day17;
+mod memory;
+mod new;
+mod run;
+mod verify;
+
+// Only counts in the `isolated` child given a memory limit
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    /// Show solver diagnostics on stderr: -v for info, -vv for debug, -vvv for trace. `RUST_LOG`
+    /// refines this per day, e.g. `RUST_LOG=day17=trace`
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    /// Split the independent work inside solvers that support it (days 6, 7, 12 and 13) across
+    /// this many threads, or every core if 0 [default: 1]
+    #[arg(long, global = true)]
+    threads: Option<usize>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run {
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        day: Option<u8>,
+        #[arg(value_enum, default_value_t = PartArg::Both)]
+        part: PartArg,
+        /// Solve every implemented day
+        #[arg(long)]
+        all: bool,
+        /// Print only the answers, one per line
+        #[arg(short, long)]
+        quiet: bool,
+        /// Input file for the day, or `-` to read stdin
+        #[arg(short, long, conflicts_with_all = ["all", "input_dir"])]
+        input: Option<String>,
+        /// Directory of `<day>.txt` inputs [default: $AOC_INPUT_DIR or inputs/]
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+        /// Also write a record per day and part to this file, as CSV if it ends in `.csv` and
+        /// JSON otherwise
+        #[arg(long)]
+        report: Option<PathBuf>,
+        /// Number of days to solve at once with --all
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+        /// Seconds a part may take before it counts as over budget, failing the run
+        #[arg(long)]
+        budget: Option<f64>,
+        /// Stop a day's solver after this many seconds and report it as over budget
+        #[arg(long)]
+        timeout: Option<f64>,
+        /// Stop a day's solver once it has allocated this many MiB and report it as over budget
+        #[arg(long)]
+        max_memory: Option<usize>,
+    },
+    /// Time parsing and solving one day, or every day, over repeated runs
+    Bench {
+        /// Day to benchmark [default: every implemented day]
+        day: Option<u8>,
+        /// Number of timed runs, after one warm-up run
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Directory of `<day>.txt` inputs [default: $AOC_INPUT_DIR or inputs/]
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+        /// Compare the medians with a baseline saved by --save-baseline
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save the medians as a baseline for later comparisons
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+        /// Slowdown compared to the baseline, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check the answers for every input against the recorded ones
+    Verify {
+        /// Directory of inputs [default: $AOC_INPUT_DIR or inputs/]
+        #[arg(long)]
+        input_dir: Option<PathBuf>,
+        /// File of recorded answers [default: answers.txt in the input directory]
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        /// Record the answers for inputs that have none yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Print a random input for a day
+    Generate {
+        day: u8,
+        /// Roughly the number of lines, or the side of the map
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+        /// Seed for the random generator; the same seed gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Tools for day 17's 3-bit computer programs
+    Day17 {
+        #[command(subcommand)]
+        command: day17::Command,
+    },
+    /// Solve a day from stdin in a process of its own, for run --timeout and --max-memory
+    #[command(hide = true)]
+    Isolated {
+        day: u8,
+        #[arg(value_enum)]
+        part: PartArg,
+        #[arg(long)]
+        input_path: PathBuf,
+        #[arg(long)]
+        max_memory: Option<usize>,
+    },
+    /// Generate the solver module, runner entry, example fixture and test for a new day
+    New {
+        day: u8,
+        /// Crate to add the day to [default: this crate]
+        #[arg(long)]
+        root: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PartArg {
+    A,
+    B,
+    Both,
+}
+
+impl PartArg {
+    fn parts(self) -> &'static [Part] {
+        match self {
+            PartArg::A => &[Part::One],
+            PartArg::B => &[Part::Two],
+            PartArg::Both => &Part::BOTH,
+        }
+    }
+}
+
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format_timestamp(None)
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    if let Some(threads) = cli.threads {
+        if let Err(error) = parallel::set_threads(threads) {
+            eprintln!("Problem starting {threads} threads: {error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            all,
+            quiet,
+            input,
+            input_dir,
+            report,
+            jobs,
+            budget,
+            timeout,
+            max_memory,
+        } => {
+            let source = match (input, input_dir) {
+                (Some(input), _) => InputSource::from_arg(&input),
+                (None, Some(dir)) => InputSource::Dir(dir),
+                (None, None) => InputSource::from_env(),
+            };
+            let solvers: Vec<&Solver> = if all {
+                runner::SOLVERS.iter().collect()
+            } else {
+                let day = day.unwrap();
+                match runner::solver(day) {
+                    Some(solver) => vec![solver],
+                    None => {
+                        eprintln!("Day {day} is not implemented");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            };
+            let options = run::Options {
+                quiet,
+                table: all,
+                jobs: jobs.max(1),
+                budget: budget.map(Duration::from_secs_f64),
+                timeout: timeout.map(Duration::from_secs_f64),
+                max_memory,
+                report,
+                verbose: cli.verbose,
+                threads: cli.threads,
+            };
+            run::run(&solvers, &source, part.parts(), &options)
+        }
+        Command::Bench {
+            day,
+            runs,
+            input_dir,
+            baseline,
+            save_baseline,
+            threshold,
+        } => {
+            let solvers: Vec<&Solver> = match day {
+                None => runner::SOLVERS.iter().collect(),
+                Some(day) => match runner::solver(day) {
+                    Some(solver) => vec![solver],
+                    None => {
+                        eprintln!("Day {day} is not implemented");
+                        return ExitCode::FAILURE;
+                    }
+                },
+            };
+            let source = input_dir.map_or_else(InputSource::from_env, InputSource::Dir);
+            let options = bench::Options {
+                runs: runs.max(1),
+                baseline,
+                save_baseline,
+                threshold,
+            };
+            bench::bench(&solvers, &source, &options)
+        }
+        Command::Verify {
+            input_dir,
+            answers,
+            record,
+        } => {
+            let dir = input_dir.unwrap_or_else(input::default_dir);
+            let answers = answers.unwrap_or_else(|| dir.join(ANSWERS_FILE));
+            verify::verify(&dir, &answers, record)
+        }
+        Command::Generate { day, size, seed } => match generate::generate(day, size, seed) {
+            Some(input) => {
+                print!("{input}");
+                ExitCode::SUCCESS
+            }
+            None => {
+                eprintln!("There is no generator for day {day}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Day17 { command } => day17::day17(command),
+        Command::Isolated {
+            day,
+            part,
+            input_path,
+            max_memory,
+        } => run::isolated(day, part.parts(), &input_path, max_memory),
+        Command::New { day, root } => {
+            let root = root.unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")));
+            new::new(&root, day)
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::process;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+
+/// The exit code of a process that went over its limit, ENOMEM's number.
+pub const OUT_OF_MEMORY: i32 = 12;
+
+/// The system allocator, keeping count of the bytes in use once [`set_limit`] is called, so it
+/// can stop the process when they go over. Until then it only checks a flag, so `run` and `bench`
+/// pay nothing measurable for it.
+pub struct Counting;
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+/// Blocks allocated before counting started and freed after take this below 0, loosening the
+/// limit by at most the memory used at that point.
+static IN_USE: AtomicIsize = AtomicIsize::new(0);
+static LIMIT: AtomicIsize = AtomicIsize::new(isize::MAX);
+
+/// Start counting, and exit with [`OUT_OF_MEMORY`] as soon as an allocation would take the bytes
+/// in use over `bytes`.
+pub fn set_limit(bytes: usize) {
+    LIMIT.store(bytes.try_into().unwrap_or(isize::MAX), Ordering::Relaxed);
+    COUNTING.store(true, Ordering::Relaxed);
+}
+
+impl Counting {
+    fn reserve(&self, size: usize) {
+        if !COUNTING.load(Ordering::Relaxed) {
+            return;
+        }
+        let in_use = IN_USE.fetch_add(size as isize, Ordering::Relaxed) + size as isize;
+        if in_use > LIMIT.load(Ordering::Relaxed) {
+            // Exiting doesn't allocate, unlike unwinding or std's allocation failure message
+            process::exit(OUT_OF_MEMORY);
+        }
+    }
+
+    fn release(&self, size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            IN_USE.fetch_sub(size as isize, Ordering::Relaxed);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.reserve(layout.size());
+        let block = System.alloc(layout);
+        if block.is_null() {
+            self.release(layout.size());
+        }
+        block
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        self.reserve(layout.size());
+        let block = System.alloc_zeroed(layout);
+        if block.is_null() {
+            self.release(layout.size());
+        }
+        block
+    }
+
+    unsafe fn dealloc(&self, block: *mut u8, layout: Layout) {
+        System.dealloc(block, layout);
+        self.release(layout.size());
+    }
+
+    unsafe fn realloc(&self, block: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if new_size > layout.size() {
+            self.reserve(new_size - layout.size());
+        }
+        let moved = System.realloc(block, layout, new_size);
+        if moved.is_null() {
+            if new_size > layout.size() {
+                self.release(new_size - layout.size());
+            }
+        } else if new_size < layout.size() {
+            self.release(layout.size() - new_size);
+        }
+        moved
+    }
+}
@@ -0,0 +1,157 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+
+const MOD_TEMPLATE: &str = r#"use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct DayN;
+
+impl Solution for DayN {
+    const DAY: u8 = N;
+
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        a::solve(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        b::solve(input)
+    }
+}
+"#;
+
+const PART_TEMPLATE: &str = r#"use crate::grid::Grid;
+
+pub fn solve(map: &Grid<char>) -> usize {
+    todo!("solve the {}x{} map", map.width(), map.height())
+}
+"#;
+
+const TEST_TEMPLATE: &str = r#"
+#[test]
+fn dayN() {
+    let input = example::<DayN>("N.txt");
+    // TODO: the answers from the puzzle statement
+    assert_eq!(DayN::part_one(&input), 0);
+    assert_eq!(DayN::part_two(&input), 0);
+}
+"#;
+
+fn fill(template: &str, day: u8) -> String {
+    template
+        .replace("DayN", &format!("Day{day}"))
+        .replace("dayN", &format!("day{day}"))
+        .replace("N.txt", &format!("{day}.txt"))
+        .replace("= N;", &format!("= {day};"))
+}
+
+/// Add `line` to the block of lines starting with `prefix`, keeping the block sorted by module
+/// name the way rustfmt does.
+fn insert_sorted(content: &str, prefix: &str, line: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let start = lines.iter().position(|l| l.starts_with(prefix));
+    let start = start.unwrap_or(lines.len());
+    let end = start
+        + lines[start..]
+            .iter()
+            .take_while(|l| l.starts_with(prefix))
+            .count();
+    let mut block = lines[start..end].to_vec();
+    block.push(line);
+    block.sort_by_key(|l| {
+        l[prefix.len()..]
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .next()
+    });
+    lines.splice(start..end, block);
+    lines.join("\n") + "\n"
+}
+
+/// Add a `Solver` for `day` to `SOLVERS`, before the first later day.
+fn register_solver(runner: &str, day: u8) -> Option<String> {
+    let start = runner.find("pub const SOLVERS")?;
+    let end = start + runner[start..].find("];")?;
+    let entry = format!("    Solver::new::<Day{day}>(),\n");
+    let position = runner[start..end]
+        .match_indices("    Solver::new::<Day")
+        .find(|&(i, _)| {
+            let rest = &runner[start + i + "    Solver::new::<Day".len()..];
+            let number: String = rest.chars().take_while(char::is_ascii_digit).collect();
+            number.parse::<u8>().is_ok_and(|other| other > day)
+        })
+        .map_or(end, |(i, _)| start + i);
+    let mut runner = runner.to_string();
+    runner.insert_str(position, &entry);
+    Some(runner)
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Option<String>) -> io::Result<()> {
+    let content = fs::read_to_string(path)?;
+    let edited = f(&content).ok_or_else(|| {
+        let message = format!("{} doesn't have the expected layout", path.display());
+        io::Error::new(io::ErrorKind::InvalidData, message)
+    })?;
+    fs::write(path, edited)
+}
+
+fn scaffold(root: &Path, day: u8) -> io::Result<()> {
+    let dir = root.join(format!("src/days/day{day}"));
+    fs::create_dir(&dir)?;
+    fs::write(dir.join("mod.rs"), fill(MOD_TEMPLATE, day))?;
+    fs::write(dir.join("a.rs"), PART_TEMPLATE)?;
+    fs::write(dir.join("b.rs"), PART_TEMPLATE)?;
+
+    let days = root.join("src/days/mod.rs");
+    edit(&days, |content| {
+        let content = insert_sorted(content, "pub mod ", &format!("pub mod day{day};"));
+        Some(insert_sorted(
+            &content,
+            "pub use ",
+            &format!("pub use day{day}::Day{day};"),
+        ))
+    })?;
+    edit(&root.join("src/runner.rs"), |content| {
+        register_solver(content, day)
+    })?;
+
+    fs::write(root.join(format!("tests/examples/{day}.txt")), "")?;
+    edit(&root.join("tests/examples.rs"), |content| {
+        Some(content.to_string() + &fill(TEST_TEMPLATE, day))
+    })
+}
+
+/// Generate the module, runner entry, example fixture and test for a new day in the crate at
+/// `root`.
+pub fn new(root: &Path, day: u8) -> ExitCode {
+    if !(1..=25).contains(&day) {
+        eprintln!("Day {day} is not an Advent of Code day");
+        return ExitCode::FAILURE;
+    }
+    if root.join(format!("src/days/day{day}")).exists() {
+        eprintln!("Day {day} already exists");
+        return ExitCode::FAILURE;
+    }
+    if let Err(error) = scaffold(root, day) {
+        eprintln!("Problem creating day {day}: {error}");
+        return ExitCode::FAILURE;
+    }
+    println!("Created src/days/day{day} and registered Day{day} with the runner");
+    println!(
+        "Paste the example into tests/examples/{day}.txt, its answers into the day{day} test in \
+         tests/examples.rs and your input into inputs/{day}.txt"
+    );
+    ExitCode::SUCCESS
+}
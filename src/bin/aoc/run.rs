@@ -0,0 +1,438 @@
+use std::env;
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2024::input::InputSource;
+use advent_of_code_2024::report::{self, Record};
+use advent_of_code_2024::runner::{self, DayResult, Part, Solver};
+use serde::{Deserialize, Serialize};
+
+use crate::memory;
+
+pub struct Options {
+    /// Print only the answers.
+    pub quiet: bool,
+    /// Print a table of every part with a summary line, instead of a line per part.
+    pub table: bool,
+    /// Number of days to solve at once.
+    pub jobs: usize,
+    /// Time a part may take before it counts as over budget.
+    pub budget: Option<Duration>,
+    /// Time a day may take before its solver is stopped.
+    pub timeout: Option<Duration>,
+    /// Memory in MiB a day's solver may allocate before it is stopped.
+    pub max_memory: Option<usize>,
+    pub report: Option<PathBuf>,
+    /// Passed on to the processes that enforce `timeout` and `max_memory`.
+    pub verbose: u8,
+    pub threads: Option<usize>,
+}
+
+impl Options {
+    /// Whether each day has to be solved in a process of its own, so it can be stopped.
+    fn isolated(&self) -> bool {
+        self.timeout.is_some() || self.max_memory.is_some()
+    }
+}
+
+/// Why a day has no answers.
+#[derive(Serialize, Deserialize)]
+pub enum Failure {
+    Read(String),
+    Parse {
+        /// The error on one line, without the source excerpt.
+        summary: String,
+        message: String,
+    },
+    Panic(String),
+    /// The solver was stopped for going over the timeout or memory limit.
+    Budget(String),
+    /// The process solving the day couldn't be started or didn't report back.
+    Process(String),
+}
+
+impl Failure {
+    pub fn summary(&self) -> String {
+        match self {
+            Failure::Read(error) | Failure::Process(error) => format!("failed: {error}"),
+            Failure::Parse { summary, .. } => format!("failed: {summary}"),
+            Failure::Panic(message) => format!("panicked: {message}"),
+            Failure::Budget(reason) => format!("budget exceeded: {reason}"),
+        }
+    }
+}
+
+/// What happened when solving one day.
+struct Outcome {
+    day: u8,
+    input_name: String,
+    input: Option<String>,
+    result: Result<DayResult, Failure>,
+}
+
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn solve_input(
+    solver: &Solver,
+    input: &str,
+    input_path: &Path,
+    parts: &[Part],
+) -> Result<DayResult, Failure> {
+    match panic::catch_unwind(AssertUnwindSafe(|| solver.run(input, parts))) {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(error)) => Err(Failure::Parse {
+            summary: format!(
+                "{}:{}: expected {}, found {}",
+                error.line, error.column, error.expected, error.found
+            ),
+            message: error.with_file(input_path).to_string(),
+        }),
+        Err(payload) => Err(Failure::Panic(panic_message(&*payload))),
+    }
+}
+
+/// Solve the day in a child process running [`isolated`], stopping it if it goes over the
+/// timeout or memory limit.
+fn solve_in_process(
+    solver: &Solver,
+    input: &str,
+    input_path: &Path,
+    parts: &[Part],
+    options: &Options,
+) -> Result<DayResult, Failure> {
+    let exe = env::current_exe().map_err(|error| Failure::Process(error.to_string()))?;
+    let mut command = Command::new(exe);
+    for _ in 0..options.verbose {
+        command.arg("-v");
+    }
+    if let Some(threads) = options.threads {
+        command.arg(format!("--threads={threads}"));
+    }
+    let part = match parts {
+        [part] => part.to_string(),
+        _ => "both".to_string(),
+    };
+    command.args(["isolated", &solver.day.to_string(), &part]);
+    command.arg(format!("--input-path={}", input_path.display()));
+    if let Some(max_memory) = options.max_memory {
+        command.arg(format!("--max-memory={max_memory}"));
+    }
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|error| Failure::Process(error.to_string()))?;
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = child.stdout.take().unwrap();
+    let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+
+    let (status, output) = thread::scope(|scope| {
+        // A child that dies early stops reading, which is fine
+        scope.spawn(move || stdin.write_all(input.as_bytes()));
+        let output = scope.spawn(move || {
+            let mut output = String::new();
+            stdout.read_to_string(&mut output).map(|_| output)
+        });
+        let status = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break Ok(Some(status)),
+                Ok(None) if deadline.is_some_and(|deadline| Instant::now() >= deadline) => {
+                    let _ = child.kill();
+                    break child.wait().map(|_| None);
+                }
+                Ok(None) => thread::sleep(Duration::from_millis(5)),
+                Err(error) => break Err(error),
+            }
+        };
+        (status, output.join().unwrap())
+    });
+
+    let status = status.map_err(|error| Failure::Process(error.to_string()))?;
+    let Some(status) = status else {
+        let timeout = options.timeout.unwrap();
+        return Err(Failure::Budget(format!("took longer than {timeout:.2?}")));
+    };
+    if status.code() == Some(memory::OUT_OF_MEMORY) {
+        let max_memory = options.max_memory.unwrap_or_default();
+        return Err(Failure::Budget(format!("used more than {max_memory} MiB")));
+    }
+    if !status.success() {
+        return Err(Failure::Process(format!("the solver process {status}")));
+    }
+    let output = output.map_err(|error| Failure::Process(error.to_string()))?;
+    serde_json::from_str(&output).map_err(|error| Failure::Process(error.to_string()))?
+}
+
+fn solve(solver: &Solver, source: &InputSource, parts: &[Part], options: &Options) -> Outcome {
+    let input_path = source
+        .path(solver.day)
+        .unwrap_or_else(|| PathBuf::from("<stdin>"));
+    let input_name = input_path.display().to_string();
+    let input = match source.read(solver.day) {
+        Ok(input) => input,
+        Err(error) => {
+            return Outcome {
+                day: solver.day,
+                input_name,
+                input: None,
+                result: Err(Failure::Read(error.to_string())),
+            }
+        }
+    };
+    let result = if options.isolated() {
+        solve_in_process(solver, &input, &input_path, parts, options)
+    } else {
+        solve_input(solver, &input, &input_path, parts)
+    };
+    Outcome {
+        day: solver.day,
+        input_name,
+        input: Some(input),
+        result,
+    }
+}
+
+fn over_budget(elapsed: Duration, budget: Option<Duration>) -> bool {
+    budget.is_some_and(|budget| elapsed > budget)
+}
+
+fn print_outcome(outcome: &Outcome, options: &Options) {
+    match &outcome.result {
+        Ok(result) => {
+            for part in &result.parts {
+                if options.quiet {
+                    println!("{}", part.answer);
+                } else {
+                    println!(
+                        "Day {}{}: {} ({:.2?})",
+                        result.day, part.part, part.answer, part.elapsed
+                    );
+                }
+                if over_budget(part.elapsed, options.budget) {
+                    eprintln!(
+                        "Day {}{} took {:.2?}, over the {:.2?} budget",
+                        result.day,
+                        part.part,
+                        part.elapsed,
+                        options.budget.unwrap()
+                    );
+                }
+            }
+        }
+        Err(Failure::Read(error)) => {
+            eprintln!("Problem reading the input for day {}: {error}", outcome.day)
+        }
+        Err(Failure::Parse { message, .. }) => {
+            eprintln!(
+                "Problem parsing the input for day {}: {message}",
+                outcome.day
+            )
+        }
+        Err(Failure::Panic(message)) => eprintln!("Day {} panicked: {message}", outcome.day),
+        Err(Failure::Budget(reason)) => {
+            eprintln!("Day {} went over budget: it {reason}", outcome.day)
+        }
+        Err(Failure::Process(error)) => {
+            eprintln!(
+                "Problem running the solver for day {}: {error}",
+                outcome.day
+            )
+        }
+    }
+}
+
+#[derive(Default)]
+struct Summary {
+    solved: usize,
+    failed: usize,
+    panicked: usize,
+    over_budget: usize,
+}
+
+fn print_table(outcomes: &[Outcome], parts: &[Part], budget: Option<Duration>, wall: Duration) {
+    let mut rows = Vec::new();
+    let mut summary = Summary::default();
+    let mut total = Duration::ZERO;
+    for outcome in outcomes {
+        match &outcome.result {
+            Ok(result) => {
+                for part in &result.parts {
+                    total += part.elapsed;
+                    let status = if over_budget(part.elapsed, budget) {
+                        summary.over_budget += 1;
+                        "over budget".to_string()
+                    } else {
+                        summary.solved += 1;
+                        "ok".to_string()
+                    };
+                    let time = format!("{:.2?}", part.elapsed);
+                    rows.push((result.day, part.part, part.answer.clone(), time, status));
+                }
+            }
+            Err(failure) => {
+                for &part in parts {
+                    match failure {
+                        Failure::Panic(_) => summary.panicked += 1,
+                        Failure::Budget(_) => summary.over_budget += 1,
+                        _ => summary.failed += 1,
+                    }
+                    let row = (outcome.day, part, "-".into(), "-".into(), failure.summary());
+                    rows.push(row);
+                }
+            }
+        }
+    }
+
+    let width = rows
+        .iter()
+        .map(|(_, _, answer, _, _)| answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    println!(
+        "{:>3}  {:4}  {:width$}  {:>10}  Status",
+        "Day", "Part", "Answer", "Time"
+    );
+    for (day, part, answer, time, status) in rows {
+        println!("{day:>3}  {part:4}  {answer:width$}  {time:>10}  {status}");
+    }
+    let total = format!("{total:.2?}");
+    println!(
+        "{:>3}  {:4}  {:width$}  {total:>10}  {} solved, {} failed, {} panicked, {} over budget \
+         ({:.2?} wall clock)",
+        "",
+        "",
+        "Total",
+        summary.solved,
+        summary.failed,
+        summary.panicked,
+        summary.over_budget,
+        wall
+    );
+}
+
+/// Solve each day with `solvers`, reading the inputs from `source`, and print the answers or a
+/// table of them. A day that fails or panics doesn't stop the others.
+pub fn run(
+    solvers: &[&Solver],
+    source: &InputSource,
+    parts: &[Part],
+    options: &Options,
+) -> ExitCode {
+    let start = Instant::now();
+    let outcomes: Vec<Outcome> = if options.jobs > 1 {
+        // Plain threads rather than a rayon pool, which would also take the solvers' own
+        // parallel work away from the pool sized by --threads
+        let next = AtomicUsize::new(0);
+        let mut outcomes: Vec<(usize, Outcome)> = thread::scope(|scope| {
+            let workers: Vec<_> = (0..options.jobs.min(solvers.len()))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut outcomes = Vec::new();
+                        loop {
+                            let i = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(solver) = solvers.get(i) else {
+                                return outcomes;
+                            };
+                            outcomes.push((i, solve(solver, source, parts, options)));
+                        }
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect()
+        });
+        outcomes.sort_by_key(|&(i, _)| i);
+        outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+    } else {
+        solvers
+            .iter()
+            .map(|solver| solve(solver, source, parts, options))
+            .collect()
+    };
+    let wall = start.elapsed();
+
+    if options.table && !options.quiet {
+        print_table(&outcomes, parts, options.budget, wall);
+    } else {
+        for outcome in &outcomes {
+            print_outcome(outcome, options);
+        }
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    let mut records = Vec::new();
+    for outcome in &outcomes {
+        match &outcome.result {
+            Ok(result) => {
+                if result
+                    .parts
+                    .iter()
+                    .any(|part| over_budget(part.elapsed, options.budget))
+                {
+                    status = ExitCode::FAILURE;
+                }
+                let input = outcome.input.as_deref().unwrap_or_default();
+                records.extend(Record::solved(result, &outcome.input_name, input));
+            }
+            Err(failure) => {
+                let error = match failure {
+                    Failure::Read(error) | Failure::Process(error) => error.clone(),
+                    Failure::Parse { summary, .. } => summary.clone(),
+                    failure => failure.summary(),
+                };
+                records.extend(Record::failed(
+                    outcome.day,
+                    parts,
+                    &outcome.input_name,
+                    outcome.input.as_deref(),
+                    &error,
+                ));
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    if let Some(path) = &options.report {
+        if let Err(error) = report::save(path, &records) {
+            eprintln!("Problem writing {}: {error}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+    status
+}
+
+/// Solve `day` with the input from stdin and print the result as JSON, for a parent process
+/// enforcing a timeout or memory limit. `input_path` is only used in error messages.
+pub fn isolated(day: u8, parts: &[Part], input_path: &Path, max_memory: Option<usize>) -> ExitCode {
+    if let Some(max_memory) = max_memory {
+        memory::set_limit(max_memory << 20);
+    }
+    let Some(solver) = runner::solver(day) else {
+        eprintln!("Day {day} is not implemented");
+        return ExitCode::FAILURE;
+    };
+    let mut input = String::new();
+    if let Err(error) = io::stdin().read_to_string(&mut input) {
+        eprintln!("Problem reading the input for day {day}: {error}");
+        return ExitCode::FAILURE;
+    }
+    let result = solve_input(solver, &input, input_path, parts);
+    println!("{}", serde_json::to_string(&result).unwrap());
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,111 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+
+use advent_of_code_2024::answers::Answers;
+use advent_of_code_2024::input;
+use advent_of_code_2024::runner::{self, Part};
+
+use crate::run::{self, Failure};
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+fn read_answers(path: &Path) -> Result<Answers, String> {
+    match fs::read_to_string(path) {
+        Ok(content) => Answers::parse(&content).map_err(|error| error.with_file(path).to_string()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(error) => Err(format!("{}: {error}", path.display())),
+    }
+}
+
+/// Run every solver on every input in `dir` and compare with the answers recorded in
+/// `answers_path`. With `record`, answers are saved for inputs that have none yet.
+pub fn verify(dir: &Path, answers_path: &Path, record: bool) -> ExitCode {
+    let mut answers = match read_answers(answers_path) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("Problem reading the answers: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let files = match input::input_files(dir) {
+        Ok(files) => files,
+        Err(error) => {
+            eprintln!("Problem listing the inputs: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut summary = Summary::default();
+    for file in files {
+        let Some(solver) = runner::solver(file.day) else {
+            continue;
+        };
+        // A panicking day counts as failed rather than stopping the others
+        let result = match fs::read_to_string(&file.path) {
+            Ok(input) => run::solve_input(solver, &input, &file.path, &Part::BOTH),
+            Err(error) => Err(Failure::Read(error.to_string())),
+        };
+        let result = match result {
+            Ok(result) => result,
+            Err(failure) => {
+                let error = match failure {
+                    Failure::Parse { message, .. } => message,
+                    failure => failure.summary(),
+                };
+                println!("Day {} [{}]: ERROR {error}", file.day, file.name);
+                summary.failed += Part::BOTH.len();
+                continue;
+            }
+        };
+        for part in result.parts {
+            let label = format!("Day {}{} [{}]", file.day, part.part, file.name);
+            match answers.get(file.day, part.part, &file.name) {
+                Some(expected) if expected == part.answer => {
+                    summary.passed += 1;
+                    println!("{label}: pass ({:.2?})", part.elapsed);
+                }
+                Some(expected) => {
+                    summary.failed += 1;
+                    println!(
+                        "{label}: FAIL got {}, expected {expected} ({:.2?})",
+                        part.answer, part.elapsed
+                    );
+                }
+                None => {
+                    summary.missing += 1;
+                    println!(
+                        "{label}: missing, got {} ({:.2?})",
+                        part.answer, part.elapsed
+                    );
+                    if record {
+                        answers.insert(file.day, part.part, &file.name, &part.answer);
+                    }
+                }
+            }
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} missing",
+        summary.passed, summary.failed, summary.missing
+    );
+    if record && summary.missing > 0 {
+        if let Err(error) = fs::write(answers_path, answers.to_string()) {
+            eprintln!("Problem writing {}: {error}", answers_path.display());
+            return ExitCode::FAILURE;
+        }
+        println!("Recorded the missing answers in {}", answers_path.display());
+    }
+    if summary.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
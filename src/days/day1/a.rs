@@ -0,0 +1,11 @@
+fn calc_diff(mut vec1: Vec<i64>, mut vec2: Vec<i64>) -> i64 {
+    vec1.sort();
+    vec2.sort();
+    vec1.iter()
+        .zip(vec2.iter())
+        .fold(0, |acc, pair| acc + (pair.1 - pair.0).abs())
+}
+
+pub fn solve(vec1: &[i64], vec2: &[i64]) -> i64 {
+    calc_diff(vec1.to_vec(), vec2.to_vec())
+}
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+fn calc_score(vec1: &[i64], vec2: &[i64]) -> i64 {
+    let mut counts = HashMap::new();
+    for num in vec2.iter() {
+        counts.insert(num, counts.get(num).unwrap_or(&0) + 1);
+    }
+    let mut score = 0;
+    for num in vec1.iter() {
+        score += num * counts.get(num).unwrap_or(&0);
+    }
+    score
+}
+
+pub fn solve(vec1: &[i64], vec2: &[i64]) -> i64 {
+    calc_score(vec1, vec2)
+}
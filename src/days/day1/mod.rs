@@ -0,0 +1,40 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<i64>, Vec<i64>);
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut vec1 = Vec::new();
+        let mut vec2 = Vec::new();
+
+        for line in input.lines() {
+            let ids = parse::numbers(input, line)?;
+            let [id1, id2] = ids[..] else {
+                return Err(ParseError::at(input, line, "two location IDs"));
+            };
+            vec1.push(id1);
+            vec2.push(id2);
+        }
+
+        Ok((vec1, vec2))
+    }
+
+    fn part_one((vec1, vec2): &Self::Input) -> i64 {
+        a::solve(vec1, vec2)
+    }
+
+    fn part_two((vec1, vec2): &Self::Input) -> i64 {
+        b::solve(vec1, vec2)
+    }
+}
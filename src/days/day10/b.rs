@@ -0,0 +1,34 @@
+use crate::geometry::Coordinate;
+use crate::grid::Grid;
+
+use super::get_trailheads;
+
+fn score_trailhead(map: &Grid<u8>, trailhead: Coordinate) -> usize {
+    let mut score = 0;
+    // let mut visited = HashSet::new();
+    let mut to_visit = vec![trailhead];
+    while let Some(current) = to_visit.pop() {
+        // if visited.contains(&current) {
+        //     continue;
+        // }
+        // visited.insert(current);
+        if map[current] == 9 {
+            score += 1;
+            continue;
+        }
+        for neighbor in map.neighbours4(current) {
+            if map[neighbor] == map[current] + 1 {
+                to_visit.push(neighbor);
+            }
+        }
+    }
+    score
+}
+
+pub fn solve(map: &Grid<u8>) -> usize {
+    let trailheads = get_trailheads(map);
+    let scores = trailheads
+        .iter()
+        .map(|&trailhead| score_trailhead(map, trailhead));
+    scores.sum::<usize>()
+}
@@ -0,0 +1,37 @@
+use crate::error::ParseError;
+use crate::geometry::Coordinate;
+use crate::grid::Grid;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day10;
+
+fn get_trailheads(map: &Grid<u8>) -> Vec<Coordinate> {
+    map.find_all(|&height| height == 0).collect()
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_with(input, |_, c| {
+            c.to_digit(10)
+                .map(|height| height as u8)
+                .ok_or("a height digit")
+        })
+    }
+
+    fn part_one(map: &Self::Input) -> usize {
+        a::solve(map)
+    }
+
+    fn part_two(map: &Self::Input) -> usize {
+        b::solve(map)
+    }
+}
@@ -0,0 +1,30 @@
+fn simulate_step(stones: &[u64]) -> Vec<u64> {
+    let mut res = Vec::new();
+    for &stone in stones {
+        if stone == 0 {
+            res.push(1);
+        } else {
+            let str = stone.to_string();
+            if str.len().is_multiple_of(2) {
+                res.push(str[0..str.len() / 2].parse().unwrap());
+                res.push(str[str.len() / 2..].parse().unwrap());
+            } else {
+                res.push(stone * 2024);
+            }
+        }
+    }
+    res
+}
+
+/// The number of stones after `blinks` blinks, simulating every stone.
+pub fn count(stones: &[u64], blinks: u8) -> usize {
+    let mut stones = stones.to_vec();
+    for _ in 0..blinks {
+        stones = simulate_step(&stones);
+    }
+    stones.len()
+}
+
+pub fn solve(stones: &[u64]) -> usize {
+    count(stones, 25)
+}
@@ -1,20 +1,6 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::time::Instant;
 
-fn read_input() -> Vec<u64> {
-    let file = File::open("inputs/11.txt");
-
-    let file = match file {
-        Ok(file) => file,
-        Err(error) => panic!("Problem opening the file: {:?}", error),
-    };
-
-    let br = BufReader::new(file);
-    let line = br.lines().next().unwrap().unwrap();
-    line.split(' ').map(|s| s.parse().unwrap()).collect()
-}
+use log::{debug, trace};
 
 fn simulate(
     stone: u64,
@@ -26,10 +12,9 @@ fn simulate(
         return 1;
     }
     if let Some(&res) = memo2.get(&(stone, steps)) {
-        // println!("Cache hit {}, {}", stone, steps);
+        trace!(target: "day11", "Cache hit {stone}, {steps}");
         return res;
     }
-    // println!("{stone}, {}, {steps}", memo1.len());
     let next = memo1
         .entry(stone)
         .or_insert_with(|| {
@@ -37,7 +22,7 @@ fn simulate(
                 vec![1]
             } else {
                 let str = stone.to_string();
-                if str.len() % 2 == 0 {
+                if str.len().is_multiple_of(2) {
                     vec![
                         str[0..str.len() / 2].parse().unwrap(),
                         str[str.len() / 2..].parse().unwrap(),
@@ -58,20 +43,23 @@ fn simulate(
     res
 }
 
-fn main() {
-    let stones = read_input();
+/// The number of stones after `blinks` blinks, memoising the count for each stone and number of
+/// blinks left.
+pub fn count(stones: &[u64], blinks: u8) -> usize {
     let mut memo1 = HashMap::new();
     let mut memo2 = HashMap::new();
     let mut count = 0;
-    let now = Instant::now();
-    for stone in stones {
-        count += simulate(stone, &mut memo1, &mut memo2, 75);
+    for &stone in stones {
+        count += simulate(stone, &mut memo1, &mut memo2, blinks);
     }
-    println!(
-        "{}ms, {}, {}",
-        now.elapsed().as_millis(),
+    debug!(
+        target: "day11", "Memoised {} expansions and {} counts",
         memo1.len(),
         memo2.len()
     );
-    println!("{}", count);
+    count
+}
+
+pub fn solve(stones: &[u64]) -> usize {
+    count(stones, 75)
 }
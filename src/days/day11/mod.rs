@@ -0,0 +1,32 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::Solution;
+
+pub mod a;
+pub mod b;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<u64>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a row of stones"))?;
+        parse::numbers(input, line)
+    }
+
+    fn part_one(stones: &Self::Input) -> usize {
+        a::solve(stones)
+    }
+
+    fn part_two(stones: &Self::Input) -> usize {
+        b::solve(stones)
+    }
+}
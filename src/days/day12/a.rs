@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+use log::debug;
+
+use crate::geometry::{Coordinate, Direction};
+use crate::grid::Grid;
+use crate::parallel;
+
+use super::segment_plots;
+
+fn calculate_perimeter(plot: &HashSet<Coordinate>) -> usize {
+    let mut res = 0;
+    for &pos in plot.iter() {
+        for dir in Direction::CARDINAL {
+            if !plot.contains(&(pos + dir)) {
+                res += 1;
+            }
+        }
+    }
+    res
+}
+
+fn score_plot(plot: &HashSet<Coordinate>) -> usize {
+    plot.len() * calculate_perimeter(plot)
+}
+
+pub fn solve(map: &Grid<char>) -> usize {
+    let plots = segment_plots(map);
+    let scores = parallel::map(&plots, |(c, plot)| (*c, score_plot(plot)));
+    debug!(target: "day12", "Scores: {scores:?}");
+    scores.iter().map(|(_, score)| score).sum::<usize>()
+}
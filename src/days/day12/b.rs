@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use log::debug;
+
+use crate::geometry::{Coordinate, Direction};
+use crate::grid::Grid;
+use crate::parallel;
+
+use super::segment_plots;
+
+fn calculate_perimeter(plot: &HashSet<Coordinate>) -> usize {
+    let x_min = plot.iter().map(|pos| pos.x).min().unwrap();
+    let x_max = plot.iter().map(|pos| pos.x).max().unwrap();
+    let y_min = plot.iter().map(|pos| pos.y).min().unwrap();
+    let y_max = plot.iter().map(|pos| pos.y).max().unwrap();
+    let mut res = 0;
+    // Count corners, which is the same as counting sides
+    for y in y_min..=y_max {
+        for x in x_min..=x_max {
+            let pos = Coordinate::new(x, y);
+            let is_in = plot.contains(&pos);
+            for dir in Direction::CARDINAL {
+                let next_dir = dir.turn_right();
+                let has_first = plot.contains(&(pos + dir));
+                let has_second = plot.contains(&(pos + next_dir));
+                let has_between = plot.contains(&(pos + dir + next_dir));
+                if is_in {
+                    if !has_first && !has_second {
+                        res += 1;
+                    }
+                } else if has_first && has_second && has_between {
+                    res += 1;
+                }
+            }
+        }
+    }
+    res
+}
+
+fn score_plot(plot: &HashSet<Coordinate>) -> usize {
+    plot.len() * calculate_perimeter(plot)
+}
+
+pub fn solve(map: &Grid<char>) -> usize {
+    let plots = segment_plots(map);
+    let scores = parallel::map(&plots, |(c, plot)| (*c, score_plot(plot)));
+    debug!(target: "day12", "Scores: {scores:?}");
+    scores.iter().map(|(_, score)| score).sum::<usize>()
+}
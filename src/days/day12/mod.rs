@@ -0,0 +1,65 @@
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::geometry::Coordinate;
+use crate::grid::Grid;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day12;
+
+fn flood_fill(
+    map: &Grid<char>,
+    start: Coordinate,
+    visited: &mut HashSet<Coordinate>,
+) -> HashSet<Coordinate> {
+    let mut res = HashSet::new();
+    let mut to_visit = vec![start];
+    while let Some(current) = to_visit.pop() {
+        if visited.contains(&current) {
+            continue;
+        }
+        if map[current] != map[start] {
+            continue;
+        }
+        visited.insert(current);
+        res.insert(current);
+        to_visit.extend(map.neighbours4(current));
+    }
+    res
+}
+
+fn segment_plots(map: &Grid<char>) -> Vec<(char, HashSet<Coordinate>)> {
+    let mut res = Vec::new();
+    let mut visited = HashSet::new();
+    for pos in map.positions() {
+        if visited.contains(&pos) {
+            continue;
+        }
+        let plot = flood_fill(map, pos, &mut visited);
+        res.push((map[pos], plot));
+    }
+    res
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part_one(map: &Self::Input) -> usize {
+        a::solve(map)
+    }
+
+    fn part_two(map: &Self::Input) -> usize {
+        b::solve(map)
+    }
+}
@@ -0,0 +1,41 @@
+use log::trace;
+
+use crate::parallel;
+
+use super::Machine;
+
+#[derive(Debug, Clone, Copy)]
+struct Solution {
+    a: isize,
+    b: isize,
+}
+
+impl Solution {
+    fn score(&self) -> usize {
+        (self.a * 3 + self.b) as usize
+    }
+
+    fn check(&self, machine: Machine) -> bool {
+        let result = machine.a * self.a + machine.b * self.b;
+        result == machine.prize
+    }
+}
+
+/// The fewest tokens that win the prize with at most 100 presses of each button, if any.
+pub fn solve_machine(machine: Machine) -> Option<usize> {
+    let mut best = None;
+    for a in 0..=100 {
+        for b in 0..=100 {
+            let solution = Solution { a, b };
+            if solution.check(machine) && (best.is_none() || solution.score() < best.unwrap()) {
+                best = Some(solution.score());
+            }
+        }
+    }
+    best
+}
+
+pub fn solve(machines: &[Machine]) -> usize {
+    trace!(target: "day13", "{machines:?}");
+    parallel::sum(machines, |&machine| solve_machine(machine).unwrap_or(0))
+}
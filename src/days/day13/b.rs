@@ -0,0 +1,128 @@
+use log::{debug, trace};
+
+use crate::geometry::Coordinate;
+use crate::parallel;
+
+use super::Machine;
+
+/// How much further away every prize is in part two.
+const PRIZE_OFFSET: Coordinate = Coordinate::new(10000000000000, 10000000000000);
+
+/// `(g, x, y)` with `u * x + v * y == g`, where `g` divides both `u` and `v`.
+fn extended_gcd(u: i128, v: i128) -> (i128, i128, i128) {
+    if v == 0 {
+        (u, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(v, u % v);
+        (g, y, x - u / v * y)
+    }
+}
+
+/// The cheapest presses `(a, b)` of buttons moving the claw `u` and `v` along one axis to total
+/// `w`, where any of them may be zero or negative.
+fn solve_line(u: isize, v: isize, w: isize) -> Option<(isize, isize)> {
+    let (u, v, w) = (u as i128, v as i128, w as i128);
+    match (u, v) {
+        (0, 0) => return (w == 0).then_some((0, 0)),
+        (_, 0) => return (w % u == 0 && w / u >= 0).then(|| ((w / u) as isize, 0)),
+        (0, _) => return (w % v == 0 && w / v >= 0).then(|| (0, (w / v) as isize)),
+        _ => {}
+    }
+    let (g, x, y) = extended_gcd(u, v);
+    if w % g != 0 {
+        return None;
+    }
+    // Every solution is a = a0 + k * p, b = b0 - k * q for some k
+    let (a0, b0, p, q) = (x * (w / g), y * (w / g), v / g, u / g);
+    let (mut lowest, mut highest) = (None, None);
+    for (start, step) in [(a0, p), (b0, -q)] {
+        // start + k * step >= 0
+        if step > 0 {
+            let bound = -start.div_euclid(step);
+            lowest = Some(lowest.map_or(bound, |lowest: i128| lowest.max(bound)));
+        } else {
+            let bound = start.div_euclid(-step);
+            highest = Some(highest.map_or(bound, |highest: i128| highest.min(bound)));
+        }
+    }
+    // The cost changes by 3 * p - q per step of k, and is never negative, so the cheapest end of
+    // the range of k is bounded
+    let k = if 3 * p - q >= 0 { lowest? } else { highest? };
+    if lowest.is_some_and(|lowest| k < lowest) || highest.is_some_and(|highest| k > highest) {
+        return None;
+    }
+    Some(((a0 + k * p) as isize, (b0 - k * q) as isize))
+}
+
+/// The cheapest presses that win the prize when both buttons move the claw along the same line.
+fn solve_dependent(machine: Machine) -> Option<(isize, isize)> {
+    let Machine { a, b, prize } = machine;
+    let direction = if a != Coordinate::ZERO { a } else { b };
+    if direction == Coordinate::ZERO {
+        trace!(target: "day13", "- Buttons don't move");
+        return (prize == Coordinate::ZERO).then_some((0, 0));
+    }
+    if direction.x * prize.y != direction.y * prize.x {
+        trace!(target: "day13", "- Prize off the line");
+        return None;
+    }
+    // Everything is on the line, so the moves along an axis the line isn't perpendicular to are
+    // enough
+    if direction.x != 0 {
+        solve_line(a.x, b.x, prize.x)
+    } else {
+        solve_line(a.y, b.y, prize.y)
+    }
+}
+
+/// The presses of A and B that win the prize for the fewest tokens, if it can be won.
+pub fn presses(machine: Machine) -> Option<(isize, isize)> {
+    // machine.a.x * a + machine.b.x * b = machine.prize.x
+    // machine.a.y * a + machine.b.y * b = machine.prize.y
+    // By Cramer's rule, with denom the determinant of the buttons:
+    // a = (machine.prize.x * machine.b.y - machine.b.x * machine.prize.y) / denom
+    // b = (machine.a.x * machine.prize.y - machine.prize.x * machine.a.y) / denom
+    let denom = machine.a.x * machine.b.y - machine.b.x * machine.a.y;
+    trace!(target: "day13", "- Denom: {denom}");
+    if denom == 0 {
+        trace!(target: "day13", "- Linearly dependent");
+        return solve_dependent(machine);
+    }
+    let a_num = machine.prize.x * machine.b.y - machine.b.x * machine.prize.y;
+    let b_num = machine.a.x * machine.prize.y - machine.prize.x * machine.a.y;
+    trace!(target: "day13", "- Nums: {a_num}, {b_num}");
+    if a_num % denom != 0 || b_num % denom != 0 {
+        trace!(target: "day13", "- Non-integer solution");
+        return None;
+    }
+    let (a, b) = (a_num / denom, b_num / denom);
+    trace!(target: "day13", "- a: {a}, b: {b}");
+    // Leaving a button unpressed is allowed, as in part one, so only negative presses are out
+    if a >= 0 && b >= 0 {
+        assert!(machine.a * a + machine.b * b == machine.prize);
+        Some((a, b))
+    } else {
+        trace!(target: "day13", "- Negative solution");
+        None
+    }
+}
+
+/// The fewest tokens that win the prize, if it can be won.
+pub fn solve_machine(machine: Machine) -> Option<usize> {
+    presses(machine).map(|(a, b)| (a as usize) * 3 + (b as usize))
+}
+
+pub fn solve(machines: &[Machine]) -> usize {
+    parallel::sum(machines, |&machine| {
+        let machine = Machine {
+            prize: machine.prize + PRIZE_OFFSET,
+            ..machine
+        };
+        debug!(target: "day13", "Machine: {machine:?}");
+        let score = solve_machine(machine);
+        if let Some(score) = score {
+            debug!(target: "day13", "- Score: {score}");
+        }
+        score.unwrap_or(0)
+    })
+}
@@ -0,0 +1,46 @@
+use crate::error::ParseError;
+use crate::geometry::Coordinate;
+use crate::parse;
+use crate::Solution;
+
+pub mod a;
+pub mod b;
+
+pub struct Day13;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    a: Coordinate,
+    b: Coordinate,
+    prize: Coordinate,
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse::paragraphs(input)
+            .map(|machine| {
+                let [a, b, prize] =
+                    parse::headers(input, machine, ["Button A", "Button B", "Prize"])?;
+                Ok(Machine {
+                    a: parse::coordinate(input, a)?,
+                    b: parse::coordinate(input, b)?,
+                    prize: parse::coordinate(input, prize)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part_one(machines: &Self::Input) -> usize {
+        a::solve(machines)
+    }
+
+    fn part_two(machines: &Self::Input) -> usize {
+        b::solve(machines)
+    }
+}
@@ -0,0 +1,30 @@
+use log::trace;
+
+use crate::geometry::Coordinate;
+
+use super::{simulate, Robot};
+
+fn score(robots: &[Robot], dim: Coordinate) -> usize {
+    let mut quadrants = [0; 4];
+    for robot in robots.iter() {
+        if robot.p.x < dim.x / 2 && robot.p.y < dim.y / 2 {
+            quadrants[0] += 1;
+        } else if robot.p.x > dim.x / 2 && robot.p.y < dim.y / 2 {
+            quadrants[1] += 1;
+        } else if robot.p.x < dim.x / 2 && robot.p.y > dim.y / 2 {
+            quadrants[2] += 1;
+        } else if robot.p.x > dim.x / 2 && robot.p.y > dim.y / 2 {
+            quadrants[3] += 1;
+        }
+    }
+    quadrants.iter().product()
+}
+
+pub fn solve(robots: &[Robot], dim: Coordinate) -> usize {
+    let mut robots = robots.to_vec();
+    for _ in 0..100 {
+        simulate(&mut robots, dim);
+    }
+    trace!(target: "day14", "{robots:?}");
+    score(&robots, dim)
+}
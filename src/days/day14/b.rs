@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+
+use log::info;
+
+use crate::geometry::Coordinate;
+use crate::grid::Grid;
+
+use super::{simulate, Robot};
+
+fn print_map(robots: &[Robot], dim: Coordinate) -> String {
+    let mut map = Grid::new(dim.x as usize, dim.y as usize, '.');
+    for robot in robots.iter() {
+        map[robot.p] = '#';
+    }
+    map.to_string()
+}
+
+pub fn solve(robots: &[Robot], dim: Coordinate) -> usize {
+    let mut robots = robots.to_vec();
+    for i in 0..dim.x * dim.y {
+        // Repeats after width * height iterations
+        simulate(&mut robots, dim);
+        // The robots only all occupy distinct tiles when they form the tree
+        let positions: HashSet<Coordinate> = robots.iter().map(|robot| robot.p).collect();
+        if positions.len() == robots.len() {
+            info!(target: "day14", "Tree after {} seconds:\n{}", i + 1, print_map(&robots, dim));
+            return i as usize + 1;
+        }
+    }
+    panic!("No tree found");
+}
@@ -0,0 +1,60 @@
+use crate::error::ParseError;
+use crate::geometry::Coordinate;
+use crate::parse;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day14;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Robot {
+    p: Coordinate,
+    v: Coordinate,
+}
+
+fn simulate(robots: &mut [Robot], dim: Coordinate) {
+    for robot in robots.iter_mut() {
+        robot.p = (robot.p + robot.v) % dim;
+    }
+}
+
+impl Day14 {
+    /// The size of the room in the real puzzle; the example uses an 11x7 room.
+    pub const ROOM: Coordinate = Coordinate::new(101, 103);
+
+    /// Part one in a room of the given size.
+    pub fn safety_factor(robots: &[Robot], room: Coordinate) -> usize {
+        a::solve(robots, room)
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Vec<Robot>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut res = Vec::new();
+        for line in input.lines() {
+            let (p, v) = line
+                .split_once(" ")
+                .ok_or_else(|| ParseError::at(input, line, "a robot like `p=0,4 v=3,-3`"))?;
+            let p = parse::coordinate(input, p)?;
+            let v = parse::coordinate(input, v)?;
+            res.push(Robot { p, v });
+        }
+        Ok(res)
+    }
+
+    fn part_one(robots: &Self::Input) -> usize {
+        Self::safety_factor(robots, Self::ROOM)
+    }
+
+    fn part_two(robots: &Self::Input) -> usize {
+        b::solve(robots, Self::ROOM)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::geometry::{Coordinate, Direction};
+use crate::grid::Grid;
+
+use super::{cell, Cell, Input};
+
+fn simulate(map: &mut Grid<Cell>, robot: &mut Coordinate, move_: Direction) {
+    let mut pos = *robot;
+    pos += move_;
+    let mut boxes = Vec::new();
+    while cell(map, pos) == Cell::Box {
+        pos += move_;
+        boxes.push(pos);
+    }
+    match cell(map, pos) {
+        Cell::Empty => {
+            for box_ in boxes {
+                map[box_] = Cell::Box;
+            }
+            *robot += move_;
+            map[*robot] = Cell::Empty;
+        }
+        Cell::Wall => {}
+        Cell::Box => unreachable!("the loop above skips boxes"),
+        Cell::BoxLeft | Cell::BoxRight => unreachable!("the narrow map has no box halves"),
+    }
+}
+
+fn score(map: &Grid<Cell>) -> usize {
+    map.find_all(|&cell| cell == Cell::Box)
+        .map(|pos| 100 * pos.y as usize + pos.x as usize)
+        .sum()
+}
+
+pub fn solve((map, robot, moves): &Input) -> usize {
+    let mut map = map.clone();
+    let mut robot = *robot;
+    for &move_ in moves {
+        simulate(&mut map, &mut robot, move_);
+    }
+    score(&map)
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+
+use crate::geometry::{Coordinate, Direction};
+use crate::grid::Grid;
+
+use super::{cell, Cell, Input};
+
+/// Double the width of everything except the robot.
+fn widen(map: &Grid<Cell>, robot: Coordinate) -> (Grid<Cell>, Coordinate) {
+    let wide = Grid::from_fn(2 * map.width(), map.height(), |pos| {
+        match (map[Coordinate::new(pos.x / 2, pos.y)], pos.x % 2) {
+            (Cell::Box, 0) => Cell::BoxLeft,
+            (Cell::Box, _) => Cell::BoxRight,
+            (cell, _) => cell,
+        }
+    });
+    (wide, Coordinate::new(2 * robot.x, robot.y))
+}
+
+fn simulate(map: &mut Grid<Cell>, robot: &mut Coordinate, move_: Direction) {
+    let mut boxes = Vec::new();
+    let mut push_locs = HashSet::from([*robot + move_]);
+    while !push_locs.is_empty() {
+        let mut next_push_locs = HashSet::new();
+        for push_loc in push_locs {
+            match cell(map, push_loc) {
+                Cell::Empty => (),
+                Cell::Wall => return,
+                Cell::Box => unreachable!("the wide map only has box halves"),
+                Cell::BoxLeft => {
+                    boxes.push(push_loc);
+                    match move_ {
+                        Direction::East => {
+                            next_push_locs.insert(push_loc + move_ + move_);
+                        }
+                        Direction::North | Direction::South => {
+                            next_push_locs.insert(push_loc + move_);
+                            next_push_locs.insert(push_loc + move_ + Direction::East);
+                        }
+                        _ => unreachable!("moves are orthogonal, and west reaches a right half"),
+                    }
+                }
+                Cell::BoxRight => {
+                    boxes.push(push_loc + Direction::West);
+                    match move_ {
+                        Direction::West => {
+                            next_push_locs.insert(push_loc + move_ + move_);
+                        }
+                        Direction::North | Direction::South => {
+                            next_push_locs.insert(push_loc + move_);
+                            next_push_locs.insert(push_loc + move_ + Direction::West);
+                        }
+                        _ => unreachable!("moves are orthogonal, and east reaches a left half"),
+                    }
+                }
+            }
+        }
+        push_locs = next_push_locs;
+    }
+
+    for &box_ in boxes.iter().rev() {
+        map[box_] = Cell::Empty;
+        map[box_ + Direction::East] = Cell::Empty;
+        map[box_ + move_] = Cell::BoxLeft;
+        map[box_ + move_ + Direction::East] = Cell::BoxRight;
+    }
+    *robot += move_;
+}
+
+fn score(map: &Grid<Cell>) -> usize {
+    map.find_all(|&cell| cell == Cell::BoxLeft)
+        .map(|pos| 100 * pos.y as usize + pos.x as usize)
+        .sum()
+}
+
+pub fn solve((map, robot, moves): &Input) -> usize {
+    let (mut map, mut robot) = widen(map, *robot);
+    for &move_ in moves {
+        simulate(&mut map, &mut robot, move_);
+    }
+    score(&map)
+}
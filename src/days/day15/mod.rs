@@ -0,0 +1,85 @@
+use crate::error::ParseError;
+use crate::geometry::{Coordinate, Direction};
+use crate::grid::Grid;
+use crate::parse;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day15;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Cell {
+    Empty,
+    Wall,
+    Box,
+    /// The halves of a box on the wide map of part two.
+    BoxLeft,
+    BoxRight,
+}
+
+/// The cell at `pos`, with walls all around the map, which needs no border of its own.
+fn cell(map: &Grid<Cell>, pos: Coordinate) -> Cell {
+    map.get(pos).copied().unwrap_or(Cell::Wall)
+}
+
+/// The map, the robot's position and its moves.
+pub type Input = (Grid<Cell>, Coordinate, Vec<Direction>);
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Input;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = parse::paragraphs(input);
+        let map_section = sections
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a map"))?;
+        let moves_section = sections.next().unwrap_or_default();
+
+        let mut robot = None;
+        let map = Grid::parse_section(input, map_section, |pos, c| match c {
+            '.' => Ok(Cell::Empty),
+            '#' => Ok(Cell::Wall),
+            'O' => Ok(Cell::Box),
+            '@' => {
+                if robot.is_some() {
+                    return Err("a single robot");
+                }
+                robot = Some(pos);
+                Ok(Cell::Empty)
+            }
+            _ => Err("one of `.`, `#`, `O` or `@`"),
+        })?;
+        let robot = robot.ok_or_else(|| ParseError::end(input, "a robot `@`"))?;
+
+        let mut moves = Vec::new();
+        for line in moves_section.lines() {
+            for (i, c) in line.char_indices() {
+                moves.push(match c {
+                    '^' => Direction::North,
+                    'v' => Direction::South,
+                    '<' => Direction::West,
+                    '>' => Direction::East,
+                    _ => {
+                        let cell = &line[i..i + c.len_utf8()];
+                        return Err(ParseError::at(input, cell, "one of `^`, `v`, `<` or `>`"));
+                    }
+                });
+            }
+        }
+        Ok((map, robot, moves))
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        a::solve(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        b::solve(input)
+    }
+}
@@ -0,0 +1,32 @@
+use log::debug;
+
+use crate::geometry::{Coordinate, Direction, Pose};
+use crate::grid::Grid;
+
+use super::{solve_maze, Cell, Input};
+
+fn render_path(map: &Grid<Cell>, poses: &[Pose], goal: Coordinate) -> String {
+    let mut rendered = map.map(|cell| match cell {
+        Cell::Free => '.',
+        Cell::Wall => '#',
+    });
+    rendered[goal] = 'E';
+    for pose in poses {
+        rendered[pose.position] = match pose.direction {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+            _ => unreachable!("poses only face cardinal directions"),
+        };
+    }
+    rendered.to_string()
+}
+
+pub fn solve(&(ref map, start, goal): &Input) -> usize {
+    let paths = solve_maze(map, start, goal);
+    if let Some(path) = paths.path() {
+        debug!(target: "day16", "Best path:\n{}", render_path(map, &path, goal));
+    }
+    paths.cost().expect("parsing checks the end is reachable")
+}
@@ -0,0 +1,20 @@
+use std::collections::HashSet;
+
+use log::debug;
+
+use crate::geometry::Coordinate;
+
+use super::{solve_maze, Input};
+
+pub fn solve(&(ref map, start, goal): &Input) -> usize {
+    let paths = solve_maze(map, start, goal);
+    let best_tiles: HashSet<Coordinate> = paths
+        .nodes_on_optimal_paths()
+        .into_iter()
+        .map(|pose| pose.position)
+        .collect();
+    if let Some(cost) = paths.cost() {
+        debug!(target: "day16", "Score: {cost}, tiles: {}", best_tiles.len());
+    }
+    best_tiles.len()
+}
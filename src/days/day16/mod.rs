@@ -0,0 +1,128 @@
+use crate::error::ParseError;
+use crate::geometry::{Coordinate, Direction, Pose};
+use crate::grid::Grid;
+use crate::search::{astar, ShortestPaths};
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day16;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Cell {
+    Free,
+    Wall,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Move {
+    Forward,
+    RotateLeft,
+    RotateRight,
+}
+
+impl Move {
+    fn apply(self, pose: Pose) -> Pose {
+        match self {
+            Move::Forward => pose.step(),
+            Move::RotateLeft => pose.turn_left(),
+            Move::RotateRight => pose.turn_right(),
+        }
+    }
+
+    fn cost(self) -> usize {
+        match self {
+            Move::Forward => 1,
+            Move::RotateLeft | Move::RotateRight => 1000,
+        }
+    }
+}
+
+/// The maze, the reindeer's starting pose and the end tile.
+pub type Input = (Grid<Cell>, Pose, Coordinate);
+
+fn solve_maze(map: &Grid<Cell>, start: Pose, goal: Coordinate) -> ShortestPaths<Pose> {
+    astar(
+        start,
+        |&pose| {
+            [Move::Forward, Move::RotateLeft, Move::RotateRight]
+                .into_iter()
+                .map(move |move_| (move_.apply(pose), move_.cost()))
+                .filter(|(new_pose, _)| map.get(new_pose.position) == Some(&Cell::Free))
+        },
+        |pose| pose.position.manhattan(goal),
+        |pose| pose.position == goal,
+    )
+}
+
+/// Whether `goal` can be reached from `start` over free tiles.
+fn reachable(map: &Grid<Cell>, start: Coordinate, goal: Coordinate) -> bool {
+    let mut seen = map.map(|_| false);
+    seen[start] = true;
+    let mut stack = vec![start];
+    while let Some(position) = stack.pop() {
+        if position == goal {
+            return true;
+        }
+        for next in map.neighbours4(position) {
+            if map[next] == Cell::Free && !seen[next] {
+                seen[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+    false
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Input;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut start = None;
+        let mut goal = None;
+        let map = Grid::parse_with(input, |position, c| match c {
+            '.' => Ok(Cell::Free),
+            '#' => Ok(Cell::Wall),
+            'S' => {
+                if start.is_some() {
+                    return Err("a single start `S`");
+                }
+                start = Some(Pose::new(position, Direction::East));
+                Ok(Cell::Free)
+            }
+            'E' => {
+                if goal.is_some() {
+                    return Err("a single end `E`");
+                }
+                goal = Some(position);
+                Ok(Cell::Free)
+            }
+            _ => Err("one of `.`, `#`, `S` or `E`"),
+        })?;
+        let start = start.ok_or_else(|| ParseError::end(input, "a start `S`"))?;
+        let goal = goal.ok_or_else(|| ParseError::end(input, "an end `E`"))?;
+        if !reachable(&map, start.position, goal) {
+            let end = input.find('E').unwrap();
+            return Err(ParseError::at(
+                input,
+                &input[end..end + 1],
+                "an end `E` reachable from the start",
+            ));
+        }
+
+        Ok((map, start, goal))
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        a::solve(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        b::solve(input)
+    }
+}
@@ -0,0 +1,13 @@
+use itertools::Itertools;
+use log::debug;
+
+use super::{asm, Input, Machine};
+
+pub fn solve((memory, program, operations): &Input) -> String {
+    debug!(target: "day17", "{memory:?}");
+    debug!(target: "day17", "Program:\n{}", asm::disassemble(operations).trim_end());
+
+    let mut machine = Machine::new(*memory);
+    let outputs = machine.run(program);
+    outputs.iter().join(",")
+}
@@ -0,0 +1,332 @@
+//! The shape of a day 17 program, found without running it.
+//!
+//! Addresses are counted in program numbers, like `jnz` targets.
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use itertools::Itertools;
+
+use super::machine::{Instruction, Operand, Operation, Register};
+
+/// Where control goes after a block.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Edge {
+    /// The block starting at this address.
+    Block(usize),
+    /// Past the end of the program, which halts.
+    End,
+    /// A jump to an odd address, which runs the operands as opcodes. The blocks don't cover
+    /// that code.
+    Misaligned(usize),
+}
+
+/// A run of operations only entered at the top and only left at the bottom.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Block {
+    pub start: usize,
+    /// The address after the last operation.
+    pub end: usize,
+    pub successors: Vec<Edge>,
+}
+
+/// An `out` and the registers, as they were when its block started, that its value depends on.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Output {
+    pub address: usize,
+    pub operand: Operand,
+    pub registers: Vec<Register>,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Termination {
+    /// Every loop shifts A right by a literal, so A reaches 0 and the program halts.
+    Halts,
+    /// The loop through this address never changes A, so it runs forever once entered with A
+    /// non-zero.
+    Loops(usize),
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Shape {
+    /// The whole program is one loop ending in `jnz 0`, and the only change to A is `adv` by a
+    /// literal, so each iteration consumes that many low bits of A. The real inputs consume 3.
+    ShiftLoop {
+        bits: u8,
+    },
+    Other,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Analysis {
+    pub blocks: Vec<Block>,
+    pub outputs: Vec<Output>,
+    pub termination: Termination,
+    pub shape: Shape,
+}
+
+/// Where a `jnz` at any address goes when it jumps.
+fn jump(operation: &Operation, len: usize) -> Edge {
+    let address = operation.operand.encode() as usize;
+    if !address.is_multiple_of(2) {
+        Edge::Misaligned(address)
+    } else if address / 2 >= len {
+        Edge::End
+    } else {
+        Edge::Block(address)
+    }
+}
+
+fn blocks(operations: &[Operation]) -> Vec<Block> {
+    let len = operations.len();
+    let mut leaders = BTreeSet::from([0]);
+    for (pc, operation) in operations.iter().enumerate() {
+        if operation.instruction == Instruction::JumpNotZero {
+            if let Edge::Block(address) = jump(operation, len) {
+                leaders.insert(address / 2);
+            }
+            leaders.insert(pc + 1);
+        }
+    }
+    leaders.retain(|&pc| pc < len);
+    let leaders: Vec<usize> = leaders.into_iter().collect();
+
+    let mut blocks = Vec::new();
+    for (i, &start) in leaders.iter().enumerate() {
+        let end = leaders.get(i + 1).copied().unwrap_or(len);
+        let next = if end < len {
+            Edge::Block(end * 2)
+        } else {
+            Edge::End
+        };
+        let last = &operations[end - 1];
+        let mut successors = Vec::new();
+        if last.instruction == Instruction::JumpNotZero {
+            successors.push(jump(last, len));
+        }
+        if !successors.contains(&next) {
+            successors.push(next);
+        }
+        blocks.push(Block {
+            start: start * 2,
+            end: end * 2,
+            successors,
+        });
+    }
+    blocks
+}
+
+/// Registers as a bit set, by their index.
+fn registers(set: u8) -> Vec<Register> {
+    [Register::A, Register::B, Register::C]
+        .into_iter()
+        .filter(|&register| set & 1 << register as u8 != 0)
+        .collect()
+}
+
+fn outputs(operations: &[Operation], blocks: &[Block]) -> Vec<Output> {
+    let mut outputs = Vec::new();
+    for block in blocks {
+        // The registers at the start of the block that each register depends on
+        let mut feeds = [0b001u8, 0b010, 0b100];
+        let (start, end) = (block.start / 2, block.end / 2);
+        for (pc, &operation) in (start..end).zip(&operations[start..end]) {
+            let Operation {
+                instruction,
+                operand,
+            } = operation;
+            let combo = match operand {
+                Operand::Register(register) => feeds[register as usize],
+                Operand::Value(_) => 0,
+            };
+            let (a, b, c) = (
+                Register::A as usize,
+                Register::B as usize,
+                Register::C as usize,
+            );
+            match instruction {
+                Instruction::DivA => feeds[a] |= combo,
+                Instruction::DivB => feeds[b] = feeds[a] | combo,
+                Instruction::DivC => feeds[c] = feeds[a] | combo,
+                Instruction::StoreB => feeds[b] = combo,
+                Instruction::BXorC => feeds[b] |= feeds[c],
+                Instruction::Output => outputs.push(Output {
+                    address: pc * 2,
+                    operand,
+                    registers: registers(combo),
+                }),
+                Instruction::XorLoadB | Instruction::JumpNotZero => {}
+            }
+        }
+    }
+    outputs
+}
+
+/// Whether the operation may change A.
+fn changes_a(operation: &Operation) -> bool {
+    operation.instruction == Instruction::DivA && operation.operand != Operand::Value(0)
+}
+
+fn termination(operations: &[Operation]) -> Termination {
+    let len = operations.len();
+    let odd_jump = operations.iter().any(|operation| {
+        operation.instruction == Instruction::JumpNotZero
+            && matches!(jump(operation, len), Edge::Misaligned(_))
+    });
+    if odd_jump {
+        return Termination::Unknown;
+    }
+    let successors = |pc: usize, a_non_zero: bool| {
+        let operation = &operations[pc];
+        let mut next = Vec::new();
+        if operation.instruction == Instruction::JumpNotZero {
+            if let Edge::Block(address) = jump(operation, len) {
+                next.push(address / 2);
+            }
+            if a_non_zero {
+                return next;
+            }
+        }
+        if pc + 1 < len {
+            next.push(pc + 1);
+        }
+        next
+    };
+
+    // Follow each jump target with A fixed and non-zero, where every `jnz` jumps
+    for operation in operations {
+        if operation.instruction != Instruction::JumpNotZero {
+            continue;
+        }
+        let Edge::Block(start) = jump(operation, len) else {
+            continue;
+        };
+        let mut seen = BTreeSet::new();
+        let mut pc = Some(start / 2);
+        while let Some(current) = pc {
+            if changes_a(&operations[current]) {
+                break;
+            }
+            if !seen.insert(current) {
+                return Termination::Loops(current * 2);
+            }
+            pc = successors(current, true).first().copied();
+        }
+    }
+
+    // Without the operations shifting A by a literal, there must be no loops left
+    let shrinks = |pc: usize| {
+        let operation = &operations[pc];
+        operation.instruction == Instruction::DivA
+            && matches!(operation.operand, Operand::Value(1..))
+    };
+    let mut state = vec![0u8; len]; // 0 unvisited, 1 on the stack, 2 done
+    for root in 0..len {
+        if state[root] != 0 || shrinks(root) {
+            continue;
+        }
+        let mut stack = vec![(root, successors(root, false))];
+        state[root] = 1;
+        while let Some((pc, next)) = stack.last_mut() {
+            let pc = *pc;
+            match next.pop() {
+                Some(next) if shrinks(next) || state[next] == 2 => {}
+                Some(next) if state[next] == 1 => return Termination::Unknown,
+                Some(next) => {
+                    state[next] = 1;
+                    stack.push((next, successors(next, false)));
+                }
+                None => {
+                    state[pc] = 2;
+                    stack.pop();
+                }
+            }
+        }
+    }
+    Termination::Halts
+}
+
+fn shape(operations: &[Operation]) -> Shape {
+    let jumps = operations
+        .iter()
+        .filter(|operation| operation.instruction == Instruction::JumpNotZero)
+        .count();
+    let shifts: Vec<&Operation> = operations.iter().filter(|op| changes_a(op)).collect();
+    match (operations.last(), shifts.as_slice()) {
+        (Some(last), [shift])
+            if jumps == 1
+                && last.instruction == Instruction::JumpNotZero
+                && last.operand == Operand::Value(0) =>
+        {
+            match shift.operand {
+                Operand::Value(bits) => Shape::ShiftLoop { bits },
+                Operand::Register(_) => Shape::Other,
+            }
+        }
+        _ => Shape::Other,
+    }
+}
+
+pub fn analyse(operations: &[Operation]) -> Analysis {
+    let blocks = blocks(operations);
+    Analysis {
+        outputs: outputs(operations, &blocks),
+        blocks,
+        termination: termination(operations),
+        shape: shape(operations),
+    }
+}
+
+impl fmt::Display for Edge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Edge::Block(address) => write!(f, "{address}"),
+            Edge::End => write!(f, "end"),
+            Edge::Misaligned(address) => write!(f, "{address} (odd, not followed)"),
+        }
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Blocks:")?;
+        for block in &self.blocks {
+            let successors = block.successors.iter().join(", ");
+            writeln!(f, "  {}..{} -> {successors}", block.start, block.end)?;
+        }
+        writeln!(f, "Outputs:")?;
+        if self.outputs.is_empty() {
+            writeln!(f, "  none")?;
+        }
+        for output in &self.outputs {
+            let registers = match output.registers.as_slice() {
+                [] => "a constant".to_string(),
+                registers => registers.iter().map(|r| format!("{r:?}")).join(", "),
+            };
+            writeln!(
+                f,
+                "  {}: out {} <- {registers}",
+                output.address, output.operand
+            )?;
+        }
+        match self.shape {
+            Shape::ShiftLoop { bits } => {
+                let plural = if bits == 1 { "" } else { "s" };
+                writeln!(
+                    f,
+                    "Shape: one loop consuming {bits} bit{plural} of A per iteration"
+                )?
+            }
+            Shape::Other => writeln!(f, "Shape: other")?,
+        }
+        match self.termination {
+            Termination::Halts => write!(f, "Termination: always halts"),
+            Termination::Loops(address) => write!(
+                f,
+                "Termination: the loop at {address} never changes A, so never ends once entered with A non-zero"
+            ),
+            Termination::Unknown => write!(f, "Termination: unknown"),
+        }
+    }
+}
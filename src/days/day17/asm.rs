@@ -0,0 +1,157 @@
+//! Day 17 programs as assembly text, and back.
+//!
+//! Each line holds an optional `label:` and an optional instruction, a mnemonic from the puzzle
+//! statement followed by its operand. Combo operands are `A`, `B`, `C` or a literal 0 to 3, and
+//! `jnz` takes a label or an address. Anything after `;` is a comment.
+//!
+//! ```text
+//! L0:
+//!     bst A
+//!     bxl 3
+//!     out B
+//!     adv 3
+//!     jnz L0
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+use crate::error::ParseError;
+
+use super::machine::{Instruction, Operand, Operation, Register};
+
+fn label(address: usize) -> String {
+    format!("L{address}")
+}
+
+/// The program as assembly, one instruction per line, with a label at every `jnz` target that
+/// is the start of an instruction or the end of the program.
+pub fn disassemble(operations: &[Operation]) -> String {
+    let targets: BTreeSet<usize> = operations
+        .iter()
+        .filter(|operation| operation.instruction == Instruction::JumpNotZero)
+        .map(|operation| operation.operand.encode() as usize)
+        .filter(|&address| address.is_multiple_of(2) && address / 2 <= operations.len())
+        .collect();
+    let mut text = String::new();
+    for (pc, operation) in operations.iter().enumerate() {
+        if targets.contains(&(pc * 2)) {
+            writeln!(text, "{}:", label(pc * 2)).unwrap();
+        }
+        match operation.instruction {
+            Instruction::JumpNotZero
+                if targets.contains(&(operation.operand.encode() as usize)) =>
+            {
+                let target = label(operation.operand.encode() as usize);
+                writeln!(text, "    jnz {target}").unwrap();
+            }
+            _ => writeln!(text, "    {operation}").unwrap(),
+        }
+    }
+    if targets.contains(&(operations.len() * 2)) {
+        writeln!(text, "{}:", label(operations.len() * 2)).unwrap();
+    }
+    text
+}
+
+fn is_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// A line without its comment and label, and the label if there is one.
+fn split_line(line: &str) -> (Option<&str>, &str) {
+    let code = line.split(';').next().unwrap();
+    match code.split_once(':') {
+        Some((name, rest)) => (Some(name.trim()), rest),
+        None => (None, code),
+    }
+}
+
+fn operand(text: &str, instruction: Instruction, token: &str) -> Result<Operand, ParseError> {
+    if instruction.takes_combo() {
+        match token {
+            "A" => Ok(Operand::Register(Register::A)),
+            "B" => Ok(Operand::Register(Register::B)),
+            "C" => Ok(Operand::Register(Register::C)),
+            "0" | "1" | "2" | "3" => Ok(Operand::Value(token.parse().unwrap())),
+            _ => Err(ParseError::at(
+                text,
+                token,
+                "a combo operand: `A`, `B`, `C` or 0 to 3",
+            )),
+        }
+    } else {
+        match token.parse() {
+            Ok(value @ 0..=7) => Ok(Operand::Value(value)),
+            _ => Err(ParseError::at(text, token, "a literal operand from 0 to 7")),
+        }
+    }
+}
+
+/// The operations written in `text`, in the format of [`disassemble`].
+pub fn assemble(text: &str) -> Result<Vec<Operation>, ParseError> {
+    // Find the labels first, so jumps can go forwards
+    let mut labels = HashMap::new();
+    let mut pc = 0;
+    for line in text.lines() {
+        let (name, code) = split_line(line);
+        if let Some(name) = name {
+            if !is_label(name) {
+                return Err(ParseError::at(text, name, "a label name"));
+            }
+            if labels.insert(name, pc * 2).is_some() {
+                return Err(ParseError::at(text, name, "a label not defined before"));
+            }
+        }
+        if !code.trim().is_empty() {
+            pc += 1;
+        }
+    }
+
+    let mut operations = Vec::new();
+    for line in text.lines() {
+        let (_, code) = split_line(line);
+        let mut tokens = code.split_whitespace();
+        let Some(mnemonic) = tokens.next() else {
+            continue;
+        };
+        let instruction = Instruction::from_mnemonic(mnemonic)
+            .ok_or_else(|| ParseError::at(text, mnemonic, "a mnemonic like `adv` or `jnz`"))?;
+        let token = tokens
+            .next()
+            .ok_or_else(|| ParseError::at(text, &code[code.len()..], "an operand"))?;
+        let operand = match labels.get(token) {
+            Some(&address) if instruction == Instruction::JumpNotZero => {
+                if address > 7 {
+                    let expected = format!("a label jnz can reach, not one at address {address}");
+                    return Err(ParseError::at(text, token, expected));
+                }
+                Operand::Value(address as u8)
+            }
+            _ if instruction == Instruction::JumpNotZero && is_label(token) => {
+                return Err(ParseError::at(text, token, "a defined label"));
+            }
+            _ => operand(text, instruction, token)?,
+        };
+        if let Some(extra) = tokens.next() {
+            return Err(ParseError::at(text, extra, "end of line"));
+        }
+        operations.push(Operation {
+            instruction,
+            operand,
+        });
+    }
+    Ok(operations)
+}
+
+/// The program as the opcodes and operands of the puzzle input.
+pub fn encode(operations: &[Operation]) -> Vec<u8> {
+    operations
+        .iter()
+        .flat_map(|operation| operation.encode())
+        .collect()
+}
@@ -0,0 +1,20 @@
+use itertools::Itertools;
+use log::debug;
+
+use super::{analysis, asm, invert, Input};
+
+pub fn solve((memory, raw_operations, operations): &Input) -> isize {
+    debug!(
+        target: "day17", "{} ({})",
+        raw_operations.iter().join(","),
+        raw_operations.len()
+    );
+    debug!(target: "day17", "Program:\n{}", asm::disassemble(operations).trim_end());
+    debug!(target: "day17", "{}", analysis::analyse(operations));
+
+    match invert::lowest_a(*memory, raw_operations, raw_operations) {
+        Ok(Some(a)) => a,
+        Ok(None) => panic!("No value of A makes the program output itself"),
+        Err(unsupported) => panic!("Can't invert the program: {unsupported}"),
+    }
+}
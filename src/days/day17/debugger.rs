@@ -0,0 +1,341 @@
+//! An interactive debugger for day 17 programs, driven by text commands.
+//!
+//! Addresses are counted in program numbers, like `jnz` targets, so the second instruction is
+//! at address 2. Odd addresses are where a jump to one reads the operands as opcodes.
+
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use itertools::Itertools;
+
+use super::machine::{Halt, Instruction, Machine, Memory, Operation, Register, Step};
+
+/// Steps kept for `reverse-step`; older ones are forgotten.
+const HISTORY_LIMIT: usize = 100_000;
+
+/// Steps `continue` takes before giving up on reaching a breakpoint.
+const CONTINUE_LIMIT: usize = 1_000_000;
+
+const HELP: &str = "\
+step [n]          execute the next n instructions (s)
+reverse-step [n]  undo the last n instructions or edits (rs)
+continue          run to a breakpoint, watchpoint or the end (c)
+break <at>        stop before the instruction at an address, or any with a mnemonic (b)
+watch <register>  stop after A, B or C changes (w)
+delete <at>       remove a breakpoint or watchpoint
+set <reg> <n>     set A, B, C or the pc (an address)
+regs              show the registers (r)
+output            show the output so far (o)
+list              show the program, marking the pc and breakpoints (l)
+quit              leave the debugger (q)";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Breakpoint {
+    Address(usize),
+    Instruction(Instruction),
+}
+
+/// Why `continue` stopped.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Stop {
+    Halt(Halt),
+    Breakpoint(Breakpoint),
+    Watchpoint {
+        register: Register,
+        before: isize,
+        after: isize,
+    },
+    /// `continue` ran for its limit of steps.
+    Limit,
+}
+
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    program: Vec<u8>,
+    machine: Machine,
+    outputs: Vec<u8>,
+    /// The machine and the number of outputs before each recent step or edit.
+    history: VecDeque<(Machine, usize)>,
+    breakpoints: Vec<Breakpoint>,
+    watchpoints: Vec<Register>,
+}
+
+fn parse_register(token: &str) -> Option<Register> {
+    match token {
+        "A" | "a" => Some(Register::A),
+        "B" | "b" => Some(Register::B),
+        "C" | "c" => Some(Register::C),
+        _ => None,
+    }
+}
+
+fn parse_count(token: Option<&str>) -> Result<usize, String> {
+    token.map_or(Ok(1), |token| {
+        token
+            .parse()
+            .map_err(|_| format!("`{token}` is not a number of steps"))
+    })
+}
+
+impl Debugger {
+    pub fn new(memory: Memory, program: Vec<u8>) -> Self {
+        Debugger {
+            program,
+            machine: Machine::new(memory),
+            outputs: Vec::new(),
+            history: VecDeque::new(),
+            breakpoints: Vec::new(),
+            watchpoints: Vec::new(),
+        }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    pub fn outputs(&self) -> &[u8] {
+        &self.outputs
+    }
+
+    /// Remember `before`, the state before a step or edit, for [`Debugger::reverse_step`].
+    fn remember(&mut self, before: (Machine, usize)) {
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.history.push_back(before);
+    }
+
+    /// Execute one instruction, remembering the state before it.
+    pub fn step(&mut self) -> Result<Step, Halt> {
+        let before = (self.machine.clone(), self.outputs.len());
+        let step = self.machine.step(&self.program)?;
+        self.remember(before);
+        self.outputs.extend(step.output);
+        Ok(step)
+    }
+
+    /// Go back to before the last instruction or edit, or return false if there is no history
+    /// left.
+    pub fn reverse_step(&mut self) -> bool {
+        let Some((machine, outputs)) = self.history.pop_back() else {
+            return false;
+        };
+        self.machine = machine;
+        self.outputs.truncate(outputs);
+        true
+    }
+
+    /// The breakpoint on the operation at `pc`, if any.
+    fn breakpoint(&self, pc: usize) -> Option<Breakpoint> {
+        let operation = Operation::at(&self.program, pc)?;
+        self.breakpoints
+            .iter()
+            .copied()
+            .find(|&breakpoint| match breakpoint {
+                Breakpoint::Address(address) => address == pc,
+                Breakpoint::Instruction(instruction) => {
+                    operation.is_ok_and(|operation| operation.instruction == instruction)
+                }
+            })
+    }
+
+    /// Run until the machine halts, reaches a breakpoint or changes a watched register. The
+    /// first instruction always runs, so continuing from a breakpoint moves past it.
+    pub fn continue_running(&mut self) -> Stop {
+        for i in 0..CONTINUE_LIMIT {
+            if i > 0 {
+                if let Some(breakpoint) = self.breakpoint(self.machine.pc) {
+                    return Stop::Breakpoint(breakpoint);
+                }
+            }
+            let step = match self.step() {
+                Ok(step) => step,
+                Err(halt) => return Stop::Halt(halt),
+            };
+            for &register in &self.watchpoints {
+                let (before, after) = (step.before.get(register), step.after.get(register));
+                if before != after {
+                    return Stop::Watchpoint {
+                        register,
+                        before,
+                        after,
+                    };
+                }
+            }
+        }
+        Stop::Limit
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    pub fn add_watchpoint(&mut self, register: Register) {
+        if !self.watchpoints.contains(&register) {
+            self.watchpoints.push(register);
+        }
+    }
+
+    /// Change a register, remembering the state before so reversing undoes just the edit.
+    pub fn set_register(&mut self, register: Register, value: isize) {
+        self.remember((self.machine.clone(), self.outputs.len()));
+        *self.machine.memory.get_mut(register) = value;
+    }
+
+    /// Move the pc to `address`, remembering the state before like [`Debugger::set_register`].
+    pub fn set_pc(&mut self, address: usize) {
+        self.remember((self.machine.clone(), self.outputs.len()));
+        self.machine.pc = address;
+    }
+
+    /// The operation at `address` as assembly, or why there is none.
+    fn describe(&self, address: usize) -> String {
+        match Operation::at(&self.program, address) {
+            Some(Ok(operation)) => operation.to_string(),
+            Some(Err(error)) => format!("({error})"),
+            None => "(end of program)".to_string(),
+        }
+    }
+
+    /// The address and next instruction, and the registers, e.g. `  4: cdv B  A=729 B=1 C=0`.
+    pub fn location(&self) -> String {
+        let address = self.machine.pc;
+        let instruction = self.describe(address);
+        let Memory { a, b, c } = self.machine.memory;
+        format!("{address:>3}: {instruction:<16} A={a} B={b} C={c}")
+    }
+
+    fn list(&self) -> String {
+        let pc = self.machine.pc;
+        // The instructions at even addresses, and the pc too if a jump made it odd
+        let mut addresses: Vec<usize> = (0..self.program.len().saturating_sub(1))
+            .step_by(2)
+            .collect();
+        if !pc.is_multiple_of(2) && pc + 1 < self.program.len() {
+            addresses.push(pc);
+            addresses.sort();
+        }
+        let mut text = String::new();
+        for address in addresses {
+            let marker = if address == pc { "=>" } else { "  " };
+            let breakpoint = if self.breakpoint(address).is_some() {
+                "*"
+            } else {
+                " "
+            };
+            let operation = self.describe(address);
+            writeln!(text, "{marker}{breakpoint}{address:>3}: {operation}").unwrap();
+        }
+        if Operation::at(&self.program, pc).is_none() {
+            writeln!(text, "=> {pc:>3}: (end of program)").unwrap();
+        }
+        text.trim_end().to_string()
+    }
+
+    fn parse_breakpoint(token: &str) -> Result<Breakpoint, String> {
+        if let Some(instruction) = Instruction::from_mnemonic(token) {
+            return Ok(Breakpoint::Instruction(instruction));
+        }
+        token
+            .parse()
+            .map(Breakpoint::Address)
+            .map_err(|_| format!("`{token}` is not an address or a mnemonic"))
+    }
+
+    /// Run a command from [`HELP`], returning what to show, or an error message.
+    pub fn execute(&mut self, command: &str) -> Result<String, String> {
+        let tokens: Vec<&str> = command.split_whitespace().collect();
+        match tokens.as_slice() {
+            [] => Ok(String::new()),
+            ["help" | "h"] => Ok(HELP.to_string()),
+            ["step" | "s", count @ ..] if count.len() <= 1 => {
+                for _ in 0..parse_count(count.first().copied())? {
+                    if let Err(halt) = self.step() {
+                        return Ok(format!("Halted: {halt}\n{}", self.location()));
+                    }
+                }
+                Ok(self.location())
+            }
+            ["reverse-step" | "rs", count @ ..] if count.len() <= 1 => {
+                for _ in 0..parse_count(count.first().copied())? {
+                    if !self.reverse_step() {
+                        return Ok(format!("No more history\n{}", self.location()));
+                    }
+                }
+                Ok(self.location())
+            }
+            ["continue" | "c"] => {
+                let stop = match self.continue_running() {
+                    Stop::Halt(halt) => format!("Halted: {halt}"),
+                    Stop::Breakpoint(Breakpoint::Address(address)) => {
+                        format!("Breakpoint at {address}")
+                    }
+                    Stop::Breakpoint(Breakpoint::Instruction(instruction)) => {
+                        format!("Breakpoint on {}", instruction.mnemonic())
+                    }
+                    Stop::Watchpoint {
+                        register,
+                        before,
+                        after,
+                    } => format!("{register:?} changed from {before} to {after}"),
+                    Stop::Limit => format!(
+                        "Still running after {CONTINUE_LIMIT} instructions; continue to go on"
+                    ),
+                };
+                Ok(format!("{stop}\n{}", self.location()))
+            }
+            ["break" | "b"] => Ok(self
+                .breakpoints
+                .iter()
+                .map(|breakpoint| match breakpoint {
+                    Breakpoint::Address(address) => address.to_string(),
+                    Breakpoint::Instruction(instruction) => instruction.mnemonic().to_string(),
+                })
+                .join(" ")),
+            ["break" | "b", at] => {
+                self.add_breakpoint(Self::parse_breakpoint(at)?);
+                Ok(String::new())
+            }
+            ["watch" | "w", register] => {
+                let register = parse_register(register)
+                    .ok_or_else(|| format!("`{register}` is not a register"))?;
+                self.add_watchpoint(register);
+                Ok(String::new())
+            }
+            ["delete", at] => {
+                if let Some(register) = parse_register(at) {
+                    self.watchpoints.retain(|&watched| watched != register);
+                } else {
+                    let breakpoint = Self::parse_breakpoint(at)?;
+                    self.breakpoints.retain(|&other| other != breakpoint);
+                }
+                Ok(String::new())
+            }
+            ["set", target, value] => {
+                let value: isize = value
+                    .parse()
+                    .map_err(|_| format!("`{value}` is not a number"))?;
+                if *target == "pc" {
+                    let address =
+                        usize::try_from(value).map_err(|_| format!("{value} is not an address"))?;
+                    self.set_pc(address);
+                } else {
+                    let register = parse_register(target)
+                        .ok_or_else(|| format!("`{target}` is not a register or `pc`"))?;
+                    self.set_register(register, value);
+                }
+                Ok(self.location())
+            }
+            ["regs" | "r"] => Ok(format!(
+                "{}\n{} instructions executed",
+                self.location(),
+                self.machine.executed
+            )),
+            ["output" | "o"] => Ok(self.outputs.iter().join(",")),
+            ["list" | "l"] => Ok(self.list()),
+            _ => Err(format!("Unknown command `{command}`; try `help`")),
+        }
+    }
+}
@@ -0,0 +1,390 @@
+//! Find the initial value of register A that makes a program output a given sequence.
+//!
+//! The program runs symbolically: every bit of every register is the XOR of some bits of the
+//! initial A and a constant. Whenever an instruction needs the value of a bit that depends on bits
+//! of A not chosen yet, like an `out`, a shift by a register or a `jnz`, the search branches on
+//! one of them. Outputs that differ from the target prune a branch straight away. Instructions
+//! whose result never reaches an `out` or a `jnz` are skipped, so they can't make it branch, and
+//! a branch that reaches a `jnz` in the same state as another, with no lower bits chosen, is cut.
+//!
+//! This makes no assumption about the shape of the program, but only handles registers that stay
+//! non-negative, and gives up on programs that need too many steps, e.g. ones that never halt.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use log::debug;
+
+use super::machine::{Halt, Instruction, Machine, Memory, Operand, Operation, Register};
+
+/// Bits of A that may be set; it is a non-negative `isize`.
+const BITS: usize = 63;
+
+/// Instructions to execute across every branch before giving up.
+const STEP_LIMIT: usize = 1_000_000;
+
+/// Why a program can't be inverted.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Unsupported {
+    /// B or C starts negative, so divisions aren't shifts.
+    NegativeRegister(Register),
+    /// The search executed this many instructions without finishing.
+    StepLimit(usize),
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unsupported::NegativeRegister(register) => {
+                write!(f, "register {register:?} starts negative")
+            }
+            Unsupported::StepLimit(steps) => {
+                write!(f, "gave up after {steps} steps; the program may not halt")
+            }
+        }
+    }
+}
+
+/// One bit of a register: the XOR of the bits of the initial A in `vars`, flipped if `flip`.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+struct Bit {
+    vars: u64,
+    flip: bool,
+}
+
+type Value = [Bit; 64];
+
+fn constant(value: isize) -> Value {
+    std::array::from_fn(|i| Bit {
+        vars: 0,
+        flip: (value >> i) & 1 == 1,
+    })
+}
+
+fn shift_right(value: &Value, shift: usize) -> Value {
+    std::array::from_fn(|i| value.get(i + shift).copied().unwrap_or_default())
+}
+
+fn xor(a: &Value, b: &Value) -> Value {
+    std::array::from_fn(|i| Bit {
+        vars: a[i].vars ^ b[i].vars,
+        flip: a[i].flip ^ b[i].flip,
+    })
+}
+
+/// A point in the search: a symbolic machine and the bits of A chosen so far.
+#[derive(Debug, Clone)]
+struct State {
+    registers: [Value; 3],
+    pc: usize,
+    outputs: usize,
+    /// Which bits of A have been chosen, and their values.
+    known: u64,
+    values: u64,
+}
+
+/// Why an instruction can't be executed yet.
+enum Need {
+    /// The value of this bit of A.
+    Var(usize),
+    /// Nothing; this branch can't give the target.
+    Pruned,
+}
+
+impl State {
+    fn bit(&self, bit: Bit) -> Result<bool, Need> {
+        let unknown = bit.vars & !self.known;
+        if unknown != 0 {
+            return Err(Need::Var(unknown.trailing_zeros() as usize));
+        }
+        Ok(((bit.vars & self.values).count_ones() % 2 == 1) ^ bit.flip)
+    }
+
+    /// The lowest `bits` bits of `value`, if they are all known.
+    fn low_bits(&self, value: &Value, bits: usize) -> Result<u64, Need> {
+        let mut result = 0;
+        for (i, &bit) in value.iter().take(bits).enumerate() {
+            result |= (self.bit(bit)? as u64) << i;
+        }
+        Ok(result)
+    }
+
+    fn combo(&self, operand: Operand) -> Value {
+        match operand {
+            Operand::Register(register) => self.registers[register as usize],
+            Operand::Value(v) => constant(v as isize),
+        }
+    }
+
+    /// A shift by `value`, capped at 64 as that leaves nothing. One known set bit from 64 up is
+    /// enough to know the cap applies, which saves choosing every bit of a shift by A.
+    fn shift(&self, value: &Value) -> Result<usize, Need> {
+        let mut need = None;
+        for &bit in &value[6..] {
+            match self.bit(bit) {
+                Ok(true) => return Ok(64),
+                Ok(false) => {}
+                Err(var) => need = need.or(Some(var)),
+            }
+        }
+        match need {
+            Some(var) => Err(var),
+            None => Ok(self.low_bits(value, 6)? as usize),
+        }
+    }
+
+    /// Whether A is non-zero; one known set bit is enough.
+    fn a_non_zero(&self) -> Result<bool, Need> {
+        let mut need = None;
+        for &bit in &self.registers[Register::A as usize] {
+            match self.bit(bit) {
+                Ok(true) => return Ok(true),
+                Ok(false) => {}
+                Err(var) => need = need.or(Some(var)),
+            }
+        }
+        need.map_or(Ok(false), Err)
+    }
+
+    /// Execute the operation at `pc`, emitting digits that must match `target`.
+    fn step(&mut self, operation: Operation, target: &[u8]) -> Result<(), Need> {
+        let mut next = self.pc + 2;
+        let operand = operation.operand;
+        match operation.instruction {
+            instruction @ (Instruction::DivA | Instruction::DivB | Instruction::DivC) => {
+                let shift = self.shift(&self.combo(operand))?;
+                let value = shift_right(&self.registers[Register::A as usize], shift);
+                let register = match instruction {
+                    Instruction::DivA => Register::A,
+                    Instruction::DivB => Register::B,
+                    _ => Register::C,
+                };
+                self.registers[register as usize] = value;
+            }
+            Instruction::XorLoadB => {
+                let b = &mut self.registers[Register::B as usize];
+                *b = xor(b, &constant(operand.encode() as isize));
+            }
+            Instruction::StoreB => {
+                let mut value = self.combo(operand);
+                value[3..].fill(Bit::default());
+                self.registers[Register::B as usize] = value;
+            }
+            Instruction::JumpNotZero => {
+                if self.a_non_zero()? {
+                    next = operand.encode() as usize;
+                }
+            }
+            Instruction::BXorC => {
+                let c = self.registers[Register::C as usize];
+                let b = &mut self.registers[Register::B as usize];
+                *b = xor(b, &c);
+            }
+            Instruction::Output => {
+                let digit = self.low_bits(&self.combo(operand), 3)? as u8;
+                if target.get(self.outputs) != Some(&digit) {
+                    return Err(Need::Pruned);
+                }
+                self.outputs += 1;
+            }
+        }
+        self.pc = next;
+        Ok(())
+    }
+
+    /// The `live` registers with the bits of A chosen so far filled in, one word per bit with the
+    /// flip on top. With `pc` and the outputs so far, this decides what the search does from here.
+    fn residual(&self, live: u8) -> Vec<u64> {
+        let mut words = Vec::new();
+        for (i, register) in self.registers.iter().enumerate() {
+            if live & 1 << i == 0 {
+                continue;
+            }
+            words.extend(register.iter().map(|bit| {
+                let flip = bit.flip ^ ((bit.vars & self.known & self.values).count_ones() % 2 == 1);
+                bit.vars & !self.known | (flip as u64) << 63
+            }));
+        }
+        words
+    }
+
+    /// The same state with bit `var` of A chosen.
+    fn choose(&self, var: usize, value: bool) -> State {
+        State {
+            known: self.known | 1 << var,
+            values: self.values | (value as u64) << var,
+            ..self.clone()
+        }
+    }
+}
+
+/// The registers an operation reads and writes, as bit sets by register index.
+fn effects(operation: Operation) -> (u8, u8) {
+    let combo = match operation.operand {
+        Operand::Register(register) => 1 << register as u8,
+        Operand::Value(_) => 0,
+    };
+    let (a, b, c) = (
+        1 << Register::A as u8,
+        1 << Register::B as u8,
+        1 << Register::C as u8,
+    );
+    match operation.instruction {
+        Instruction::DivA => (a | combo, a),
+        Instruction::DivB => (a | combo, b),
+        Instruction::DivC => (a | combo, c),
+        Instruction::XorLoadB => (b, b),
+        Instruction::StoreB => (combo, b),
+        Instruction::JumpNotZero => (a, 0),
+        Instruction::BXorC => (b | c, b),
+        Instruction::Output => (combo, 0),
+    }
+}
+
+/// For each address, the registers whose value after the operation there may still reach an `out`
+/// or a `jnz`. Reads by operations whose own result never does don't count.
+fn live_after(program: &[u8]) -> Vec<u8> {
+    let operations: Vec<_> = (0..program.len())
+        .map(|pc| match Operation::at(program, pc) {
+            Some(Ok(operation)) => Some(operation),
+            _ => None,
+        })
+        .collect();
+    let mut live_before = vec![0u8; program.len()];
+    let mut live_after = vec![0u8; program.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (pc, operation) in operations.iter().enumerate().rev() {
+            // The machine stops here, so nothing is read again
+            let Some(operation) = *operation else {
+                continue;
+            };
+            let mut after = live_before.get(pc + 2).copied().unwrap_or(0);
+            if operation.instruction == Instruction::JumpNotZero {
+                after |= live_before
+                    .get(operation.operand.encode() as usize)
+                    .copied()
+                    .unwrap_or(0);
+            }
+            let (reads, writes) = effects(operation);
+            let used = writes == 0 || writes & after != 0;
+            let before = after & !writes | if used { reads } else { 0 };
+            if (before, after) != (live_before[pc], live_after[pc]) {
+                (live_before[pc], live_after[pc]) = (before, after);
+                changed = true;
+            }
+        }
+    }
+    live_after
+}
+
+/// The lowest initial A that makes the raw program output exactly `target` and halt at its end
+/// when B and C start as in `memory`, or `None` if there is none.
+pub fn lowest_a(
+    memory: Memory,
+    program: &[u8],
+    target: &[u8],
+) -> Result<Option<isize>, Unsupported> {
+    for register in [Register::B, Register::C] {
+        if memory.get(register) < 0 {
+            return Err(Unsupported::NegativeRegister(register));
+        }
+    }
+    // The search fixes the highest set bit of A, so 0 is tried first on its own
+    let zero = Memory { a: 0, ..memory };
+    let run = Machine::new(zero)
+        .with_limit(STEP_LIMIT)
+        .run_until_halt(program);
+    if run == (target.to_vec(), Halt::End) {
+        return Ok(Some(0));
+    }
+
+    let a: Value = std::array::from_fn(|i| Bit {
+        vars: if i < BITS { 1 << i } else { 0 },
+        flip: false,
+    });
+    let live = live_after(program);
+    let mut steps = 0;
+
+    // Try each position of A's highest set bit in turn, so the first one with any solutions
+    // holds the lowest
+    for highest in 0..BITS {
+        let above = !0u64 << highest;
+        let start = State {
+            registers: [a, constant(memory.b), constant(memory.c)],
+            pc: 0,
+            outputs: 0,
+            known: above,
+            values: 1 << highest,
+        };
+        let mut lowest: Option<u64> = None;
+        // The lowest bits chosen when reaching a jump in each residual state
+        let mut seen = HashMap::new();
+        let mut stack = vec![start];
+        'search: while let Some(mut state) = stack.pop() {
+            // Bits not chosen yet can be 0, so this branch can't beat a lower answer
+            if lowest.is_some_and(|lowest| state.values >= lowest) {
+                continue;
+            }
+            loop {
+                let Some(operation) = Operation::at(program, state.pc) else {
+                    if state.outputs == target.len() {
+                        // Bits never needed can be 0
+                        let value = state.values;
+                        lowest = Some(lowest.map_or(value, |lowest| lowest.min(value)));
+                    }
+                    continue 'search;
+                };
+                steps += 1;
+                if steps > STEP_LIMIT {
+                    return Err(Unsupported::StepLimit(STEP_LIMIT));
+                }
+                // The machine stops on a reserved operand without reaching the end
+                let Ok(operation) = operation else {
+                    continue 'search;
+                };
+                // Whatever this would write is never read, so needs no bits of A
+                let (_, writes) = effects(operation);
+                if writes != 0 && writes & live[state.pc] == 0 {
+                    state.pc += 2;
+                    continue;
+                }
+                // The same answers as the branch seen here before, plus bits at least as high
+                if operation.instruction == Instruction::JumpNotZero {
+                    let live = live[state.pc] | 1 << Register::A as u8;
+                    let key = (state.pc, state.outputs, state.residual(live));
+                    let seen = seen.entry(key).or_insert(u64::MAX);
+                    if *seen <= state.values {
+                        continue 'search;
+                    }
+                    *seen = state.values;
+                }
+                match state.step(operation, target) {
+                    Ok(()) => {}
+                    Err(Need::Pruned) => continue 'search,
+                    Err(Need::Var(var)) => {
+                        stack.push(state.choose(var, true));
+                        stack.push(state.choose(var, false));
+                        continue 'search;
+                    }
+                }
+            }
+        }
+        if let Some(lowest) = lowest {
+            debug!(target: "day17", "Searched {steps} steps");
+            let lowest = lowest as isize;
+            let memory = Memory {
+                a: lowest,
+                ..memory
+            };
+            debug_assert_eq!(
+                Machine::new(memory).run_until_halt(program),
+                (target.to_vec(), Halt::End)
+            );
+            return Ok(Some(lowest));
+        }
+    }
+    debug!(target: "day17", "Searched {steps} steps");
+    Ok(None)
+}
@@ -0,0 +1,354 @@
+//! The 3-bit computer, run to completion or one instruction at a time.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Instruction {
+    DivA,        // adv
+    XorLoadB,    // bxl
+    StoreB,      // bst
+    JumpNotZero, // jnz
+    BXorC,       // bxc
+    Output,      // out
+    DivB,        // bdv
+    DivC,        // cdv
+}
+
+impl Instruction {
+    /// The instruction for an opcode, or `None` if it is above 7.
+    pub fn from_int(i: u8) -> Option<Self> {
+        Instruction::ALL.get(i as usize).copied()
+    }
+
+    pub fn opcode(self) -> u8 {
+        self as u8
+    }
+
+    pub const ALL: [Instruction; 8] = [
+        Instruction::DivA,
+        Instruction::XorLoadB,
+        Instruction::StoreB,
+        Instruction::JumpNotZero,
+        Instruction::BXorC,
+        Instruction::Output,
+        Instruction::DivB,
+        Instruction::DivC,
+    ];
+
+    /// The three-letter name from the puzzle statement, e.g. `adv`.
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Instruction::DivA => "adv",
+            Instruction::XorLoadB => "bxl",
+            Instruction::StoreB => "bst",
+            Instruction::JumpNotZero => "jnz",
+            Instruction::BXorC => "bxc",
+            Instruction::Output => "out",
+            Instruction::DivB => "bdv",
+            Instruction::DivC => "cdv",
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Instruction::ALL
+            .into_iter()
+            .find(|instruction| instruction.mnemonic() == mnemonic)
+    }
+
+    /// Whether the operand is a combo operand, which can name a register, rather than a literal.
+    pub fn takes_combo(self) -> bool {
+        !matches!(
+            self,
+            Instruction::XorLoadB | Instruction::JumpNotZero | Instruction::BXorC
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Register {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Operand {
+    Register(Register),
+    Value(u8),
+}
+
+impl Operand {
+    pub fn resolve(&self, memory: &Memory) -> isize {
+        match self {
+            Operand::Register(register) => memory.get(*register),
+            Operand::Value(v) => *v as isize,
+        }
+    }
+
+    /// The operand as written in the program, between 0 and 7.
+    pub fn encode(self) -> u8 {
+        match self {
+            Operand::Value(v) => v,
+            Operand::Register(Register::A) => 4,
+            Operand::Register(Register::B) => 5,
+            Operand::Register(Register::C) => 6,
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{register:?}"),
+            Operand::Value(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+/// Why two numbers of a program aren't an operation.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DecodeError {
+    /// An opcode above 7.
+    Opcode(u8),
+    /// An operand above 7, or the reserved combo operand 7.
+    Operand(u8),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Opcode(opcode) => write!(f, "{opcode} is not an opcode"),
+            DecodeError::Operand(operand) => write!(f, "{operand} is not a valid operand"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Operation {
+    pub instruction: Instruction,
+    pub operand: Operand,
+}
+
+impl Operation {
+    /// The operation for an opcode and operand, both between 0 and 7 and the operand not the
+    /// reserved combo operand 7.
+    pub fn decode(opcode: u8, operand: u8) -> Result<Self, DecodeError> {
+        let instruction = Instruction::from_int(opcode).ok_or(DecodeError::Opcode(opcode))?;
+        let operand = match operand {
+            0..=7 if !instruction.takes_combo() => Operand::Value(operand),
+            0..=3 => Operand::Value(operand),
+            4 => Operand::Register(Register::A),
+            5 => Operand::Register(Register::B),
+            6 => Operand::Register(Register::C),
+            _ => return Err(DecodeError::Operand(operand)),
+        };
+        Ok(Operation {
+            instruction,
+            operand,
+        })
+    }
+
+    /// The operation at `address` in a raw program, or `None` past its end.
+    pub fn at(program: &[u8], address: usize) -> Option<Result<Self, DecodeError>> {
+        match program.get(address..address.checked_add(2)?)? {
+            &[opcode, operand] => Some(Operation::decode(opcode, operand)),
+            _ => None,
+        }
+    }
+
+    /// The opcode and operand as written in the program.
+    pub fn encode(self) -> [u8; 2] {
+        [self.instruction.opcode(), self.operand.encode()]
+    }
+}
+
+/// The operation in assembly, e.g. `bst A`.
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.instruction.mnemonic(), self.operand)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Memory {
+    pub a: isize,
+    pub b: isize,
+    pub c: isize,
+}
+
+impl Memory {
+    pub fn get(&self, register: Register) -> isize {
+        match register {
+            Register::A => self.a,
+            Register::B => self.b,
+            Register::C => self.c,
+        }
+    }
+
+    pub fn get_mut(&mut self, register: Register) -> &mut isize {
+        match register {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+            Register::C => &mut self.c,
+        }
+    }
+}
+
+/// Why the machine stopped.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Halt {
+    /// The instruction pointer moved past the end of the program, the normal way to stop.
+    End,
+    /// The machine executed as many instructions as its limit allows.
+    Limit,
+    /// The numbers at this address aren't an operation, e.g. after a jump to an odd address
+    /// read a 7 as a combo operand.
+    Invalid(usize, DecodeError),
+    /// A division by 2 to a negative power.
+    NegativeShift(isize),
+}
+
+impl fmt::Display for Halt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Halt::End => write!(f, "reached the end of the program"),
+            Halt::Limit => write!(f, "reached the instruction limit"),
+            Halt::Invalid(address, error) => write!(f, "can't execute {address}: {error}"),
+            Halt::NegativeShift(shift) => write!(f, "divided by 2 to the power {shift}"),
+        }
+    }
+}
+
+/// One executed instruction.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Step {
+    /// Address of the operation.
+    pub pc: usize,
+    pub operation: Operation,
+    /// The operand's value, after looking up combo registers.
+    pub operand: isize,
+    pub before: Memory,
+    pub after: Memory,
+    pub output: Option<u8>,
+}
+
+/// `numerator / 2^shift`, rounding towards zero, or `None` for a negative shift.
+fn divide(numerator: isize, shift: isize) -> Option<isize> {
+    match shift {
+        ..0 => None,
+        0..63 => Some(numerator / (1 << shift)),
+        _ => Some(0),
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Machine {
+    pub memory: Memory,
+    /// Address of the next opcode. Odd addresses are fine, reading operands as opcodes.
+    pub pc: usize,
+    /// Instructions executed so far.
+    pub executed: usize,
+    /// Most instructions to execute, or `None` for no limit.
+    pub limit: Option<usize>,
+}
+
+impl Machine {
+    pub fn new(memory: Memory) -> Self {
+        Machine {
+            memory,
+            pc: 0,
+            executed: 0,
+            limit: None,
+        }
+    }
+
+    /// Stop after executing `limit` instructions.
+    pub fn with_limit(self, limit: usize) -> Self {
+        Machine {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Execute the operation at the pc of the raw program, or say why the machine can't.
+    pub fn step(&mut self, program: &[u8]) -> Result<Step, Halt> {
+        let operation = Operation::at(program, self.pc).ok_or(Halt::End)?;
+        if self.limit.is_some_and(|limit| self.executed >= limit) {
+            return Err(Halt::Limit);
+        }
+        let operation = operation.map_err(|error| Halt::Invalid(self.pc, error))?;
+        let pc = self.pc;
+        let before = self.memory;
+        let operand = operation.operand.resolve(&self.memory);
+        let mut next = self.pc + 2;
+        let mut output = None;
+        match operation.instruction {
+            Instruction::DivA => {
+                self.memory.a =
+                    divide(self.memory.a, operand).ok_or(Halt::NegativeShift(operand))?;
+            }
+            Instruction::XorLoadB => {
+                self.memory.b ^= operand;
+            }
+            Instruction::StoreB => {
+                self.memory.b = operand & 0b111;
+            }
+            Instruction::JumpNotZero => {
+                if self.memory.a != 0 {
+                    next = operand as usize;
+                }
+            }
+            Instruction::BXorC => {
+                self.memory.b ^= self.memory.c;
+            }
+            Instruction::Output => {
+                output = Some((operand & 0b111) as u8);
+            }
+            Instruction::DivB => {
+                self.memory.b =
+                    divide(self.memory.a, operand).ok_or(Halt::NegativeShift(operand))?;
+            }
+            Instruction::DivC => {
+                self.memory.c =
+                    divide(self.memory.a, operand).ok_or(Halt::NegativeShift(operand))?;
+            }
+        }
+        self.pc = next;
+        self.executed += 1;
+        Ok(Step {
+            pc,
+            operation,
+            operand,
+            before,
+            after: self.memory,
+            output,
+        })
+    }
+
+    /// Run until the machine halts, returning the outputs and why it stopped.
+    pub fn run_until_halt(&mut self, program: &[u8]) -> (Vec<u8>, Halt) {
+        let mut outputs = Vec::new();
+        loop {
+            match self.step(program) {
+                Ok(step) => outputs.extend(step.output),
+                Err(halt) => return (outputs, halt),
+            }
+        }
+    }
+
+    /// Run until the machine halts, returning the outputs.
+    pub fn run(&mut self, program: &[u8]) -> Vec<u8> {
+        self.run_until_halt(program).0
+    }
+
+    /// Run until the machine halts, returning every executed instruction and why it stopped.
+    pub fn trace(&mut self, program: &[u8]) -> (Vec<Step>, Halt) {
+        let mut steps = Vec::new();
+        loop {
+            match self.step(program) {
+                Ok(step) => steps.push(step),
+                Err(halt) => return (steps, halt),
+            }
+        }
+    }
+}
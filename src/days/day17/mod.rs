@@ -0,0 +1,80 @@
+use crate::error::{parse_number, ParseError};
+use crate::parse;
+use crate::Solution;
+
+mod a;
+pub mod analysis;
+pub mod asm;
+mod b;
+pub mod debugger;
+pub mod invert;
+pub mod machine;
+
+pub use machine::{Machine, Memory, Operation};
+
+pub struct Day17;
+
+/// The initial registers, the raw program the machine runs, and the operations at its even
+/// addresses for disassembly and analysis.
+pub type Input = (Memory, Vec<u8>, Vec<Operation>);
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Input;
+    type PartOne = String;
+    type PartTwo = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let [a, b, c, program] = parse::headers(
+            input,
+            input,
+            ["Register A", "Register B", "Register C", "Program"],
+        )?;
+        let memory = Memory {
+            a: parse_number(input, a)?,
+            b: parse_number(input, b)?,
+            c: parse_number(input, c)?,
+        };
+        let tokens: Vec<&str> = program.split(',').map(str::trim).collect();
+        let mut raw_operations = Vec::new();
+        let mut operations = Vec::new();
+        for chunk in tokens.chunks(2) {
+            let opcode: u8 = parse_number(input, chunk[0])?;
+            if opcode > 7 {
+                return Err(ParseError::at(input, chunk[0], "an opcode between 0 and 7"));
+            }
+            let Some(&operand_token) = chunk.get(1) else {
+                return Err(ParseError::at(
+                    input,
+                    &program[program.len()..],
+                    "an operand",
+                ));
+            };
+            let operand: u8 = parse_number(input, operand_token)?;
+            if operand > 7 {
+                return Err(ParseError::at(
+                    input,
+                    operand_token,
+                    "an operand between 0 and 7",
+                ));
+            }
+            let operation = Operation::decode(opcode, operand).map_err(|_| {
+                ParseError::at(input, operand_token, "a combo operand between 0 and 6")
+            })?;
+            raw_operations.push(opcode);
+            raw_operations.push(operand);
+            operations.push(operation);
+        }
+
+        Ok((memory, raw_operations, operations))
+    }
+
+    fn part_one(input: &Self::Input) -> String {
+        a::solve(input)
+    }
+
+    fn part_two(input: &Self::Input) -> isize {
+        b::solve(input)
+    }
+}
@@ -0,0 +1,26 @@
+fn count_safe(report: &[Vec<i16>]) -> usize {
+    let mut count = 0;
+    for row in report.iter() {
+        let increasing = row[1] > row[0];
+        let mut safe = true;
+        for i in 1..row.len() {
+            if (row[i] > row[i - 1]) != increasing {
+                safe = false;
+                break;
+            }
+            let diff = (row[i] - row[i - 1]).abs();
+            if !(1..=3).contains(&diff) {
+                safe = false;
+                break;
+            }
+        }
+        if safe {
+            count += 1;
+        }
+    }
+    count
+}
+
+pub fn solve(report: &[Vec<i16>]) -> usize {
+    count_safe(report)
+}
@@ -0,0 +1,55 @@
+fn is_safe(levels: &[i16]) -> bool {
+    first_unsafe(levels).is_none()
+}
+
+/// The index of the first level that breaks the direction set by the first two levels or
+/// differs from the previous one by less than 1 or more than 3. A single level is safe.
+fn first_unsafe(levels: &[i16]) -> Option<usize> {
+    if levels.len() < 2 {
+        return None;
+    }
+    let increasing = levels[1] > levels[0];
+    for i in 1..levels.len() {
+        if (levels[i] > levels[i - 1]) != increasing {
+            return Some(i);
+        }
+        let diff = (levels[i] - levels[i - 1]).abs();
+        if !(1..=3).contains(&diff) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+fn without(levels: &[i16], i: usize) -> Vec<i16> {
+    levels
+        .iter()
+        .enumerate()
+        .filter(|(j, _)| *j != i)
+        .map(|(_, v)| *v)
+        .collect()
+}
+
+/// Whether the levels are safe after removing at most one of them, trying every removal.
+pub fn is_safe_dampened_brute_force(levels: &[i16]) -> bool {
+    is_safe(levels) || (0..levels.len()).any(|i| is_safe(&without(levels, i)))
+}
+
+/// Whether the levels are safe after removing at most one of them. Only the two levels around
+/// the first unsafe step, or one of the first two levels to change the direction, can help.
+pub fn is_safe_dampened(levels: &[i16]) -> bool {
+    let Some(i) = first_unsafe(levels) else {
+        return true;
+    };
+    [0, 1, i - 1, i]
+        .into_iter()
+        .any(|j| is_safe(&without(levels, j)))
+}
+
+fn count_safe(report: &[Vec<i16>]) -> usize {
+    report.iter().filter(|row| is_safe_dampened(row)).count()
+}
+
+pub fn solve(report: &[Vec<i16>]) -> usize {
+    count_safe(report)
+}
@@ -0,0 +1,36 @@
+use crate::error::ParseError;
+use crate::parse;
+use crate::Solution;
+
+pub mod a;
+pub mod b;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i16>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut res = Vec::new();
+        for line in input.lines() {
+            let levels = parse::numbers(input, line)?;
+            if levels.len() < 2 {
+                return Err(ParseError::at(input, line, "at least two levels"));
+            }
+            res.push(levels);
+        }
+        Ok(res)
+    }
+
+    fn part_one(report: &Self::Input) -> usize {
+        a::solve(report)
+    }
+
+    fn part_two(report: &Self::Input) -> usize {
+        b::solve(report)
+    }
+}
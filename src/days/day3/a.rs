@@ -1,10 +1,5 @@
-use std::fs;
 use regex::Regex;
 
-fn read_input() -> String {
-    fs::read_to_string("inputs/3.txt").expect("File not found")
-}
-
 struct Mul {
     x: i32,
     y: i32,
@@ -22,7 +17,7 @@ fn extract_muls(code: &str) -> Vec<Mul> {
     res
 }
 
-fn add_muls(muls: &Vec<Mul>) -> i32 {
+fn add_muls(muls: &[Mul]) -> i32 {
     let mut res = 0;
     for mul in muls {
         res += mul.x * mul.y;
@@ -30,9 +25,7 @@ fn add_muls(muls: &Vec<Mul>) -> i32 {
     res
 }
 
-fn main() {
-    let code = read_input();
-    let muls = extract_muls(&code);
-    let count = add_muls(&muls);
-    println!("{}", count);
+pub fn solve(code: &str) -> i32 {
+    let muls = extract_muls(code);
+    add_muls(&muls)
 }
@@ -1,9 +1,4 @@
 use regex::Regex;
-use std::fs;
-
-fn read_input() -> String {
-    fs::read_to_string("inputs/3.txt").expect("File not found")
-}
 
 enum Op {
     Mul(i32, i32),
@@ -27,7 +22,7 @@ fn extract_ops(code: &str) -> Vec<Op> {
     res
 }
 
-fn add_muls(ops: &Vec<Op>) -> i32 {
+fn add_muls(ops: &[Op]) -> i32 {
     let mut res = 0;
     let mut enable = true;
     for mul in ops {
@@ -48,9 +43,7 @@ fn add_muls(ops: &Vec<Op>) -> i32 {
     res
 }
 
-fn main() {
-    let code = read_input();
-    let ops = extract_ops(&code);
-    let count = add_muls(&ops);
-    println!("{}", count);
+pub fn solve(code: &str) -> i32 {
+    let ops = extract_ops(code);
+    add_muls(&ops)
 }
@@ -0,0 +1,27 @@
+use crate::error::ParseError;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Input = String;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(code: &Self::Input) -> i32 {
+        a::solve(code)
+    }
+
+    fn part_two(code: &Self::Input) -> i32 {
+        b::solve(code)
+    }
+}
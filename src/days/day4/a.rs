@@ -0,0 +1,27 @@
+use crate::geometry::Direction;
+use crate::grid::Grid;
+
+use super::get_chars;
+
+fn count_occurences(puzzle: &Grid<char>, target: &str, dir: Direction) -> usize {
+    let mut count = 0;
+    for loc in puzzle.positions() {
+        if let Some(chars) = get_chars(puzzle, loc, dir, target.len()) {
+            if chars.iter().collect::<String>() == target {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn count_all_occurences(puzzle: &Grid<char>, target: &str) -> usize {
+    Direction::ALL
+        .into_iter()
+        .map(|dir| count_occurences(puzzle, target, dir))
+        .sum()
+}
+
+pub fn solve(puzzle: &Grid<char>) -> usize {
+    count_all_occurences(puzzle, "XMAS")
+}
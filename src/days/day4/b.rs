@@ -0,0 +1,37 @@
+use crate::geometry::Direction;
+use crate::grid::Grid;
+
+use super::get_chars;
+
+fn count_occurences(puzzle: &Grid<char>) -> usize {
+    let mut count = 0;
+    const TARGET: &str = "MAS";
+    for loc in puzzle.positions() {
+        for dir1 in [Direction::NorthWest, Direction::SouthEast] {
+            for dir2 in [Direction::NorthEast, Direction::SouthWest] {
+                let loc1 = loc - dir1;
+                let loc2 = loc - dir2;
+                if let Some(chars1) = get_chars(puzzle, loc1, dir1, TARGET.len()) {
+                    if chars1.iter().collect::<String>() != TARGET {
+                        continue;
+                    }
+                } else {
+                    continue;
+                }
+                if let Some(chars2) = get_chars(puzzle, loc2, dir2, TARGET.len()) {
+                    if chars2.iter().collect::<String>() != TARGET {
+                        continue;
+                    }
+                } else {
+                    continue;
+                }
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub fn solve(puzzle: &Grid<char>) -> usize {
+    count_occurences(puzzle)
+}
@@ -0,0 +1,43 @@
+use crate::error::ParseError;
+use crate::geometry::{Coordinate, Direction};
+use crate::grid::Grid;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day4;
+
+fn get_chars(
+    puzzle: &Grid<char>,
+    location: Coordinate,
+    dir: Direction,
+    count: usize,
+) -> Option<Vec<char>> {
+    let res: Vec<char> = puzzle
+        .ray(location, dir)
+        .take(count)
+        .map(|loc| puzzle[loc])
+        .collect();
+    (res.len() == count).then_some(res)
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part_one(puzzle: &Self::Input) -> usize {
+        a::solve(puzzle)
+    }
+
+    fn part_two(puzzle: &Self::Input) -> usize {
+        b::solve(puzzle)
+    }
+}
@@ -0,0 +1,20 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{get_middle_item, is_valid_update, rules_to_mapping};
+
+fn get_valid_updates(rules: &HashMap<u8, HashSet<u8>>, updates: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    updates
+        .iter()
+        .filter(|update| is_valid_update(rules, update))
+        .cloned()
+        .collect()
+}
+
+pub fn solve(rules: &[(u8, u8)], updates: &[Vec<u8>]) -> usize {
+    let rules = rules_to_mapping(rules);
+    let valid_updates = get_valid_updates(&rules, updates);
+    valid_updates
+        .iter()
+        .map(|update| get_middle_item(update) as usize)
+        .sum::<usize>()
+}
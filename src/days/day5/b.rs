@@ -0,0 +1,55 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{get_middle_item, is_valid_update, rules_to_mapping};
+
+fn get_invalid_updates(rules: &HashMap<u8, HashSet<u8>>, updates: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    updates
+        .iter()
+        .filter(|update| !is_valid_update(rules, update))
+        .cloned()
+        .collect()
+}
+
+fn fix_update_once(rules: &HashMap<u8, HashSet<u8>>, update: &[u8]) -> Vec<u8> {
+    let mut illegal: HashSet<u8> = HashSet::new();
+    illegal.extend(rules.get(&update[0]).unwrap_or(&HashSet::new()));
+    let mut fixed = vec![update[0]];
+    for &page in &update[1..] {
+        if illegal.contains(&page) {
+            fixed.insert(0, page);
+        } else {
+            fixed.push(page);
+        }
+        illegal.extend(rules.get(&page).unwrap_or(&HashSet::new()));
+    }
+    fixed
+}
+
+fn fix_update(rules: &HashMap<u8, HashSet<u8>>, update: &[u8]) -> Vec<u8> {
+    let mut update = update.to_vec();
+    while !is_valid_update(rules, &update) {
+        update = fix_update_once(rules, &update);
+    }
+    update
+}
+
+fn fix_updates(rules: &HashMap<u8, HashSet<u8>>, updates: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    updates
+        .iter()
+        .map(|update| fix_update(rules, update))
+        .collect()
+}
+
+fn score(updates: &[Vec<u8>]) -> usize {
+    updates
+        .iter()
+        .map(|update| get_middle_item(update) as usize)
+        .sum::<usize>()
+}
+
+pub fn solve(rules: &[(u8, u8)], updates: &[Vec<u8>]) -> usize {
+    let rules = rules_to_mapping(rules);
+    let invalid_updates = get_invalid_updates(&rules, updates);
+    let fixed_updates = fix_updates(&rules, &invalid_updates);
+    score(&fixed_updates)
+}
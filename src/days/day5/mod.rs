@@ -0,0 +1,82 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseError;
+use crate::parse;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day5;
+
+fn rules_to_mapping(rules: &[(u8, u8)]) -> HashMap<u8, HashSet<u8>> {
+    let mut mapping = HashMap::new();
+    for rule in rules {
+        mapping
+            .entry(rule.1)
+            .or_insert(HashSet::new())
+            .insert(rule.0);
+    }
+    mapping
+}
+
+fn is_valid_update(rules: &HashMap<u8, HashSet<u8>>, update: &[u8]) -> bool {
+    let mut illegal: HashSet<u8> = HashSet::new();
+    illegal.extend(rules.get(&update[0]).unwrap_or(&HashSet::new()));
+    for page in &update[1..] {
+        if illegal.contains(page) {
+            return false;
+        }
+        illegal.extend(rules.get(page).unwrap_or(&HashSet::new()));
+    }
+    true
+}
+
+fn get_middle_item(update: &[u8]) -> u8 {
+    assert!(update.len() % 2 == 1);
+    update[update.len() / 2]
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Input = (Vec<(u8, u8)>, Vec<Vec<u8>>);
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = parse::paragraphs(input);
+        let rules_section = sections
+            .next()
+            .ok_or_else(|| ParseError::end(input, "page ordering rules"))?;
+        let updates_section = sections.next().unwrap_or_default();
+
+        let mut rules = Vec::new();
+        let mut updates = Vec::new();
+        for line in rules_section.lines() {
+            let line = line.trim();
+            let pages = parse::numbers_separated_by(input, line, '|')?;
+            let [before, after] = pages[..] else {
+                return Err(ParseError::at(input, line, "a rule like `47|53`"));
+            };
+            rules.push((before, after));
+        }
+        for line in updates_section.lines() {
+            let line = line.trim();
+            let update = parse::numbers_separated_by(input, line, ',')?;
+            if update.len() % 2 == 0 {
+                return Err(ParseError::at(input, line, "an odd number of pages"));
+            }
+            updates.push(update);
+        }
+        Ok((rules, updates))
+    }
+
+    fn part_one((rules, updates): &Self::Input) -> usize {
+        a::solve(rules, updates)
+    }
+
+    fn part_two((rules, updates): &Self::Input) -> usize {
+        b::solve(rules, updates)
+    }
+}
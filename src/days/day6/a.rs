@@ -0,0 +1,6 @@
+use super::{simulate, Input};
+
+pub fn solve((map, start): &Input) -> usize {
+    let visited = simulate(map, *start);
+    visited.len()
+}
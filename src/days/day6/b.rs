@@ -0,0 +1,45 @@
+use std::collections::HashSet;
+
+use log::info;
+
+use crate::geometry::Pose;
+use crate::grid::Grid;
+use crate::parallel;
+
+use super::{simulate, simulate_once, Input, State};
+
+fn will_loop(map: &Grid<State>, mut guard: Pose) -> bool {
+    let mut visited = HashSet::new();
+    while !visited.contains(&guard) {
+        visited.insert(guard);
+        match simulate_once(map, guard) {
+            Some(new_guard) => {
+                guard = new_guard;
+            }
+            None => {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn count_looping_obstacle_locations(map: &Grid<State>, guard: Pose) -> usize {
+    let candidate_locations: Vec<_> = simulate(map, guard).into_iter().enumerate().collect();
+    let num_candidates = candidate_locations.len();
+    parallel::count(&candidate_locations, |&(i, loc)| {
+        if i % 100 == 0 {
+            info!(target: "day6", "Checking location {i}/{num_candidates}");
+        }
+        if loc == guard.position {
+            return false;
+        }
+        let mut new_map = map.clone();
+        new_map[loc] = State::Occupied;
+        will_loop(&new_map, guard)
+    })
+}
+
+pub fn solve((map, start): &Input) -> usize {
+    count_looping_obstacle_locations(map, *start)
+}
@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::geometry::{Coordinate, Direction, Pose};
+use crate::grid::Grid;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day6;
+
+#[derive(Clone, Eq, PartialEq)]
+pub enum State {
+    Empty,
+    Occupied,
+}
+
+pub type Input = (Grid<State>, Pose);
+
+fn simulate_once(map: &Grid<State>, guard: Pose) -> Option<Pose> {
+    let new_loc = guard.ahead();
+    match map.get(new_loc)? {
+        State::Occupied => simulate_once(map, guard.turn_right()),
+        State::Empty => Some(guard.step()),
+    }
+}
+
+fn simulate(map: &Grid<State>, mut guard: Pose) -> HashSet<Coordinate> {
+    let mut visited = HashSet::new();
+    loop {
+        visited.insert(guard.position);
+        match simulate_once(map, guard) {
+            Some(new_guard) => {
+                guard = new_guard;
+            }
+            None => {
+                break;
+            }
+        }
+    }
+    visited
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Input;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut start: Option<Pose> = None;
+        let map = Grid::parse_with(input, |pos, c| match c {
+            '.' => Ok(State::Empty),
+            '#' => Ok(State::Occupied),
+            '^' => {
+                if start.is_some() {
+                    return Err("a single guard");
+                }
+                start = Some(Pose::new(pos, Direction::North));
+                Ok(State::Empty)
+            }
+            _ => Err("one of `.`, `#` or `^`"),
+        })?;
+        let start = start.ok_or_else(|| ParseError::end(input, "a guard `^`"))?;
+        Ok((map, start))
+    }
+
+    fn part_one(input: &Self::Input) -> usize {
+        a::solve(input)
+    }
+
+    fn part_two(input: &Self::Input) -> usize {
+        b::solve(input)
+    }
+}
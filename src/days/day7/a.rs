@@ -0,0 +1,59 @@
+use log::trace;
+
+use crate::parallel;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Operation {
+    Add,
+    Multiply,
+}
+
+fn calculate_result(operands: &[u64], operations: &[Operation]) -> u64 {
+    assert_eq!(operands.len(), operations.len() + 1);
+    let mut res = operands[0];
+    for (operation, operand) in operations.iter().zip(&operands[1..]) {
+        match operation {
+            Operation::Add => res += operand,
+            Operation::Multiply => res *= operand,
+        }
+    }
+    res
+}
+
+fn can_give_result(operands: &[u64], target: u64) -> bool {
+    assert!(operands.len() > 1);
+    let mut operations = vec![Operation::Add; operands.len() - 1];
+    loop {
+        if calculate_result(operands, &operations) == target {
+            return true;
+        }
+        let mut i = operations.len() - 1;
+        while i > 0 && operations[i] == Operation::Multiply {
+            i -= 1;
+        }
+        if operations[i] == Operation::Multiply {
+            break;
+        }
+        operations[i] = Operation::Multiply;
+        for operation in operations.iter_mut().skip(i + 1) {
+            *operation = Operation::Add;
+        }
+    }
+    false
+}
+
+fn calculate_total(input: &[(u64, Vec<u64>)]) -> u64 {
+    parallel::sum(input, |(result, operands)| {
+        if can_give_result(operands, *result) {
+            trace!(target: "day7", "{result} can be given by {operands:?}");
+            *result
+        } else {
+            trace!(target: "day7", "{result} cannot be given by {operands:?}");
+            0
+        }
+    })
+}
+
+pub fn solve(input: &[(u64, Vec<u64>)]) -> u64 {
+    calculate_total(input)
+}
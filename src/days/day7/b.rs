@@ -0,0 +1,63 @@
+use itertools::Itertools;
+use log::trace;
+
+use crate::parallel;
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+enum Operation {
+    Add,
+    Mul,
+    Con,
+}
+
+fn calculate_result(operands: &[u64], operations: &[Operation], limit: u64) -> Option<u64> {
+    assert_eq!(operands.len(), operations.len() + 1);
+    let mut res = operands[0];
+    for (operation, operand) in operations.iter().zip(&operands[1..]) {
+        match operation {
+            Operation::Add => res += operand,
+            Operation::Mul => res *= operand,
+            Operation::Con => {
+                res = (res.to_string() + &operand.to_string())
+                    .parse::<u64>()
+                    .unwrap()
+            }
+        }
+        if res > limit {
+            return None;
+        }
+    }
+    Some(res)
+}
+
+fn can_give_result(operands: &[u64], target: u64) -> bool {
+    assert!(operands.len() > 1);
+    for operators in itertools::repeat_n(
+        [Operation::Add, Operation::Mul, Operation::Con].into_iter(),
+        operands.len() - 1,
+    )
+    .multi_cartesian_product()
+    {
+        let result = calculate_result(operands, &operators, target);
+        if result == Some(target) {
+            return true;
+        }
+    }
+    false
+}
+
+fn calculate_total(input: &[(u64, Vec<u64>)]) -> u64 {
+    parallel::sum(input, |(result, operands)| {
+        if can_give_result(operands, *result) {
+            trace!(target: "day7", "{result} can be given by {operands:?}");
+            *result
+        } else {
+            trace!(target: "day7", "{result} cannot be given by {operands:?}");
+            0
+        }
+    })
+}
+
+pub fn solve(input: &[(u64, Vec<u64>)]) -> u64 {
+    calculate_total(input)
+}
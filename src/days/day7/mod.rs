@@ -0,0 +1,42 @@
+use crate::error::{parse_number, ParseError};
+use crate::parse;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(u64, Vec<u64>)>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut res = Vec::new();
+
+        for line in input.lines() {
+            let (result, operands) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(input, line, "an equation like `190: 10 19`"))?;
+            let result = parse_number::<u64>(input, result)?;
+            let operands: Vec<u64> = parse::numbers(input, operands)?;
+            if operands.len() < 2 {
+                return Err(ParseError::at(input, line, "at least two operands"));
+            }
+            res.push((result, operands));
+        }
+
+        Ok(res)
+    }
+
+    fn part_one(input: &Self::Input) -> u64 {
+        a::solve(input)
+    }
+
+    fn part_two(input: &Self::Input) -> u64 {
+        b::solve(input)
+    }
+}
@@ -0,0 +1,32 @@
+use itertools::Itertools;
+use log::trace;
+
+use crate::grid::Grid;
+
+use super::Antennas;
+
+fn map_antinodes(antennas: &Antennas) -> Grid<bool> {
+    let mut map = Grid::new(antennas.width, antennas.height, false);
+    for (c, locations) in &antennas.locations {
+        trace!(target: "day8", "Locations for {c}: {locations:?}");
+        for (&loc1, &loc2) in locations.iter().tuple_combinations() {
+            trace!(target: "day8", "Checking {loc1:?} and {loc2:?}");
+            let delta = loc2 - loc1;
+            for node in [loc1 - delta, loc2 + delta] {
+                if let Some(cell) = map.get_mut(node) {
+                    trace!(target: "day8", "Setting {node:?} to true");
+                    *cell = true;
+                } else {
+                    trace!(target: "day8", "Skipping {node:?} as out of bounds");
+                }
+            }
+        }
+    }
+    map
+}
+
+pub fn solve(antennas: &Antennas) -> usize {
+    let map = map_antinodes(antennas);
+    trace!(target: "day8", "Antinodes:\n{map}");
+    map.find_all(|&b| b).count()
+}
@@ -0,0 +1,29 @@
+use gcd::Gcd;
+use itertools::Itertools;
+
+use crate::grid::Grid;
+
+use super::Antennas;
+
+fn map_antinodes(antennas: &Antennas) -> Grid<bool> {
+    let mut map = Grid::new(antennas.width, antennas.height, false);
+    let size = antennas.width.max(antennas.height) as isize;
+    for locations in antennas.locations.values() {
+        for (&loc1, &loc2) in locations.iter().tuple_combinations() {
+            let delta = loc2 - loc1;
+            let gcd = delta.x.unsigned_abs().gcd(delta.y.unsigned_abs()) as isize;
+            let step = delta / gcd;
+            for i in -size..size {
+                if let Some(cell) = map.get_mut(loc1 + step * i) {
+                    *cell = true;
+                }
+            }
+        }
+    }
+    map
+}
+
+pub fn solve(antennas: &Antennas) -> usize {
+    let map = map_antinodes(antennas);
+    map.find_all(|&b| b).count()
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::error::ParseError;
+use crate::geometry::Coordinate;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day8;
+
+pub struct Antennas {
+    width: usize,
+    height: usize,
+    locations: HashMap<char, Vec<Coordinate>>,
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Antennas;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut antenna_locations = HashMap::new();
+        let mut width = 0;
+        let mut height = 0;
+        for (i, line) in input.lines().enumerate() {
+            let line = line.trim();
+            width = width.max(line.chars().count());
+            height = i + 1;
+            for (j, (offset, c)) in line.char_indices().enumerate() {
+                if c == '.' {
+                    continue;
+                }
+                if !c.is_ascii_alphanumeric() {
+                    let cell = &line[offset..offset + c.len_utf8()];
+                    return Err(ParseError::at(input, cell, "`.` or an antenna frequency"));
+                }
+                antenna_locations
+                    .entry(c)
+                    .or_insert(Vec::new())
+                    .push(Coordinate::new(j as isize, i as isize));
+            }
+        }
+        Ok(Antennas {
+            width,
+            height,
+            locations: antenna_locations,
+        })
+    }
+
+    fn part_one(antennas: &Self::Input) -> usize {
+        a::solve(antennas)
+    }
+
+    fn part_two(antennas: &Self::Input) -> usize {
+        b::solve(antennas)
+    }
+}
@@ -1,19 +1,4 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-fn read_input() -> Vec<u8> {
-    let file = File::open("inputs/9.txt");
-
-    let file = match file {
-        Ok(file) => file,
-        Err(error) => panic!("Problem opening the file: {:?}", error),
-    };
-
-    let br = BufReader::new(file);
-
-    let line = br.lines().next().unwrap().unwrap();
-    line.chars().map(|c| c.to_string().parse::<u8>().unwrap()).collect()
-}
+use log::trace;
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 enum Block {
@@ -21,7 +6,7 @@ enum Block {
     File(usize),
 }
 
-fn unpack_disk_map(disk_map: &Vec<u8>) -> Vec<Block> {
+fn unpack_disk_map(disk_map: &[u8]) -> Vec<Block> {
     let mut res = Vec::new();
     let mut id: usize = 0;
     let mut file = true;
@@ -37,7 +22,7 @@ fn unpack_disk_map(disk_map: &Vec<u8>) -> Vec<Block> {
     res
 }
 
-fn compress(disk_map: &Vec<Block>) -> Vec<usize> {
+fn compress(disk_map: &[Block]) -> Vec<usize> {
     let mut res = Vec::new();
     let mut i = 0;
     let mut j = disk_map.len() - 1;
@@ -62,7 +47,7 @@ fn compress(disk_map: &Vec<Block>) -> Vec<usize> {
     res
 }
 
-fn compute_checksum(compressed: &Vec<usize>) -> usize {
+fn compute_checksum(compressed: &[usize]) -> usize {
     let mut sum = 0;
     for (i, &id) in compressed.iter().enumerate() {
         sum += i * id;
@@ -70,11 +55,10 @@ fn compute_checksum(compressed: &Vec<usize>) -> usize {
     sum
 }
 
-fn main() {
-    let disk_map = read_input();
-    let unpacked = unpack_disk_map(&disk_map);
+pub fn solve(disk_map: &[u8]) -> usize {
+    let unpacked = unpack_disk_map(disk_map);
+    trace!(target: "day9", "Unpacked: {unpacked:?}");
     let compressed = compress(&unpacked);
-    // println!("{:?}", unpacked);
-    // println!("{:?}", compressed);
-    println!("{}", compute_checksum(&compressed));
+    trace!(target: "day9", "Compressed: {compressed:?}");
+    compute_checksum(&compressed)
 }
@@ -1,21 +1,4 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-
-fn read_input() -> Vec<u8> {
-    let file = File::open("inputs/9.txt");
-
-    let file = match file {
-        Ok(file) => file,
-        Err(error) => panic!("Problem opening the file: {:?}", error),
-    };
-
-    let br = BufReader::new(file);
-
-    let line = br.lines().next().unwrap().unwrap();
-    line.chars()
-        .map(|c| c.to_string().parse::<u8>().unwrap())
-        .collect()
-}
+use log::trace;
 
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 struct Block {
@@ -23,7 +6,7 @@ struct Block {
     id: Option<usize>,
 }
 
-fn unpack_disk_map(disk_map: &Vec<u8>) -> Vec<Block> {
+fn unpack_disk_map(disk_map: &[u8]) -> Vec<Block> {
     let mut res = Vec::new();
     let mut id: usize = 0;
     let mut file = true;
@@ -40,15 +23,15 @@ fn unpack_disk_map(disk_map: &Vec<u8>) -> Vec<Block> {
     res
 }
 
-fn compress(disk_map: &Vec<Block>) -> Vec<Block> {
-    let mut res = disk_map.clone();
+fn compress(disk_map: &[Block]) -> Vec<Block> {
+    let mut res = disk_map.to_vec();
     let mut i = 0;
     let mut j = res.len() - 1;
     while j > 0 {
         if let Block { id: Some(_), size } = res[j] {
-            // println!("Trying to insert {:?}", res[j]);
+            trace!(target: "day9", "Trying to insert {:?}", res[j]);
             while i <= j {
-                // println!("Trying to insert {:?} at {:?}", res[j], res[i]);
+                trace!(target: "day9", "Trying to insert {:?} at {:?}", res[j], res[i]);
                 if res[i].id.is_some() || res[i].size < size {
                     i += 1;
                     continue;
@@ -71,7 +54,7 @@ fn compress(disk_map: &Vec<Block>) -> Vec<Block> {
                         j += 1;
                     }
                 }
-                // println!("{:?}", res);
+                trace!(target: "day9", "{res:?}");
                 break;
             }
         }
@@ -81,7 +64,7 @@ fn compress(disk_map: &Vec<Block>) -> Vec<Block> {
     res
 }
 
-fn compute_checksum(disk_map: &Vec<Block>) -> usize {
+fn compute_checksum(disk_map: &[Block]) -> usize {
     let mut sum = 0;
     let mut i = 0;
     for &block in disk_map.iter() {
@@ -97,11 +80,10 @@ fn compute_checksum(disk_map: &Vec<Block>) -> usize {
     sum
 }
 
-fn main() {
-    let disk_map = read_input();
-    let unpacked = unpack_disk_map(&disk_map);
+pub fn solve(disk_map: &[u8]) -> usize {
+    let unpacked = unpack_disk_map(disk_map);
+    trace!(target: "day9", "Unpacked: {unpacked:?}");
     let compressed = compress(&unpacked);
-    // println!("{:?}", unpacked);
-    // println!("{:?}", compressed);
-    println!("{}", compute_checksum(&compressed));
+    trace!(target: "day9", "Compressed: {compressed:?}");
+    compute_checksum(&compressed)
 }
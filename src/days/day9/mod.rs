@@ -0,0 +1,38 @@
+use crate::error::ParseError;
+use crate::Solution;
+
+mod a;
+mod b;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::end(input, "a disk map"))?
+            .trim();
+        line.char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or_else(|| ParseError::at(input, &line[i..i + c.len_utf8()], "a digit"))
+            })
+            .collect()
+    }
+
+    fn part_one(disk_map: &Self::Input) -> usize {
+        a::solve(disk_map)
+    }
+
+    fn part_two(disk_map: &Self::Input) -> usize {
+        b::solve(disk_map)
+    }
+}
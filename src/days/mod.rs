@@ -0,0 +1,35 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub use day1::Day1;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+pub use day13::Day13;
+pub use day14::Day14;
+pub use day15::Day15;
+pub use day16::Day16;
+pub use day17::Day17;
+pub use day2::Day2;
+pub use day3::Day3;
+pub use day4::Day4;
+pub use day5::Day5;
+pub use day6::Day6;
+pub use day7::Day7;
+pub use day8::Day8;
+pub use day9::Day9;
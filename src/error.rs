@@ -0,0 +1,88 @@
+use std::any::type_name;
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// A problem with the puzzle input, located by line and column.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    source_line: String,
+}
+
+impl ParseError {
+    /// An error at `fragment`, which must be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        let found = if !fragment.is_empty() {
+            let mut found: String = fragment.chars().take(20).collect();
+            if found.len() < fragment.len() {
+                found.push_str("...");
+            }
+            format!("`{found}`")
+        } else if offset == input.len() {
+            "end of input".to_string()
+        } else if offset == line_end || &input[offset..line_end] == "\r" {
+            "end of line".to_string()
+        } else {
+            "nothing".to_string()
+        };
+        ParseError {
+            file: None,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            found,
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// An error reporting that `input` ended too early.
+    pub fn end(input: &str, expected: impl Into<String>) -> Self {
+        ParseError::at(input, &input[input.len()..], expected)
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let file = match &self.file {
+            Some(file) => file.display().to_string(),
+            None => "<input>".to_string(),
+        };
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "expected {}, found {}", self.expected, self.found)?;
+        writeln!(f, "{gutter}--> {file}:{}:{}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `token`, a slice of `input`, reporting its location on failure.
+pub fn parse_number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(input, token, format!("a `{}`", type_name::<T>())))
+}
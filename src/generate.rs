@@ -0,0 +1,415 @@
+//! Random puzzle inputs, for stress-testing the solvers beyond the real inputs.
+//!
+//! Every generator takes a `size`, roughly the number of lines or the side of the map, and
+//! produces an input in the same format as the real one, which the day's parser accepts.
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+
+use crate::days::day17::{invert, Memory};
+
+type Generator = fn(&mut StdRng, usize) -> String;
+
+const GENERATORS: &[Generator] = &[
+    day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13, day14, day15,
+    day16, day17,
+];
+
+/// A random input for `day`, the same for the same `size` and `seed`, or `None` if there is no
+/// generator for the day.
+pub fn generate(day: u8, size: usize, seed: u64) -> Option<String> {
+    let generator = GENERATORS.get(usize::from(day).checked_sub(1)?)?;
+    let mut rng = StdRng::seed_from_u64(seed);
+    Some(generator(&mut rng, size.max(1)))
+}
+
+/// A `width` by `height` map, one row per line, with each cell drawn by `cell`.
+fn map(
+    rng: &mut StdRng,
+    width: usize,
+    height: usize,
+    mut cell: impl FnMut(&mut StdRng) -> char,
+) -> String {
+    let mut map = String::new();
+    for _ in 0..height {
+        map.extend((0..width).map(|_| cell(rng)));
+        map.push('\n');
+    }
+    map
+}
+
+/// Replace a random cell of a map made by [`map`] with `c`, avoiding the border if `inner`.
+fn place(rng: &mut StdRng, map: &mut String, c: char, inner: bool) {
+    let width = map.find('\n').unwrap();
+    let height = map.len() / (width + 1);
+    let margin = usize::from(inner && width > 2 && height > 2);
+    let x = rng.gen_range(margin..width - margin);
+    let y = rng.gen_range(margin..height - margin);
+    let i = y * (width + 1) + x;
+    map.replace_range(i..i + 1, c.encode_utf8(&mut [0; 4]));
+}
+
+/// Location ID pairs.
+fn day1(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let (a, b) = (rng.gen_range(10000..100000), rng.gen_range(10000..100000));
+        writeln!(input, "{a}   {b}").unwrap();
+    }
+    input
+}
+
+/// Reports of levels that mostly change by small steps.
+fn day2(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let mut level: i16 = rng.gen_range(1..100);
+        let mut levels = vec![level];
+        for _ in 0..rng.gen_range(4..8) {
+            level += rng.gen_range(-4..=4);
+            levels.push(level);
+        }
+        writeln!(input, "{}", levels.iter().join(" ")).unwrap();
+    }
+    input
+}
+
+/// Memory with `mul`, `do` and `don't` instructions among corrupted ones.
+fn day3(rng: &mut StdRng, size: usize) -> String {
+    const NOISE: &[&str] = &[
+        "mul(4*",
+        "mul[3,7]",
+        "?(12,34)",
+        "mul ( 2 , 4 )",
+        "select()",
+        "from()",
+        "how()",
+        "%&",
+        "[",
+        ")",
+        "'",
+        "don't",
+        "do(",
+        "mul(,5)",
+        "#",
+    ];
+    let mut input = String::new();
+    for _ in 0..size {
+        match rng.gen_range(0..6) {
+            0..=2 => {
+                let (x, y) = (rng.gen_range(0..1000), rng.gen_range(0..1000));
+                write!(input, "mul({x},{y})").unwrap();
+            }
+            3 => input.push_str("do()"),
+            4 => input.push_str("don't()"),
+            _ => input.push_str(NOISE.choose(rng).unwrap()),
+        }
+        input.push_str(NOISE.choose(rng).unwrap());
+    }
+    input.push('\n');
+    input
+}
+
+/// A word search of `X`, `M`, `A` and `S`.
+fn day4(rng: &mut StdRng, size: usize) -> String {
+    map(rng, size, size, |rng| *b"XMAS".choose(rng).unwrap() as char)
+}
+
+/// Page ordering rules for every pair of pages, consistent with one total order, and updates of
+/// an odd number of those pages.
+fn day5(rng: &mut StdRng, size: usize) -> String {
+    let mut pages: Vec<u8> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(size.clamp(3, pages.len()));
+
+    let mut rules: Vec<(u8, u8)> = pages.iter().copied().tuple_combinations().collect();
+    rules.shuffle(rng);
+    let mut input = String::new();
+    for (before, after) in rules {
+        writeln!(input, "{before}|{after}").unwrap();
+    }
+    input.push('\n');
+    for _ in 0..size {
+        let len = rng.gen_range(1..=pages.len().min(23).div_ceil(2)) * 2 - 1;
+        let update = pages.choose_multiple(rng, len);
+        writeln!(input, "{}", update.map(u8::to_string).join(",")).unwrap();
+    }
+    input
+}
+
+/// A lab map with obstructions and a guard facing north, redrawn until the guard walks off it
+/// rather than patrolling forever.
+fn day6(rng: &mut StdRng, size: usize) -> String {
+    loop {
+        let mut lab = map(
+            rng,
+            size,
+            size,
+            |rng| if rng.gen_bool(0.1) { '#' } else { '.' },
+        );
+        place(rng, &mut lab, '^', false);
+        if guard_leaves(&lab) {
+            return lab;
+        }
+    }
+}
+
+/// Whether the guard on a day 6 map walks off it, turning right at every obstruction.
+fn guard_leaves(lab: &str) -> bool {
+    let rows: Vec<&[u8]> = lab.lines().map(str::as_bytes).collect();
+    let start = lab.find('^').unwrap();
+    let width = rows[0].len() as isize;
+    let (mut x, mut y) = (start as isize % (width + 1), start as isize / (width + 1));
+    let (mut dx, mut dy) = (0, -1);
+    let mut seen = HashSet::new();
+    while seen.insert((x, y, dx, dy)) {
+        let (nx, ny) = (x + dx, y + dy);
+        if nx < 0 || ny < 0 || nx >= width || ny >= rows.len() as isize {
+            return true;
+        }
+        if rows[ny as usize][nx as usize] == b'#' {
+            (dx, dy) = (-dy, dx);
+        } else {
+            (x, y) = (nx, ny);
+        }
+    }
+    false
+}
+
+/// Calibration equations, half of which can be made true with `+`, `*` and `||`.
+fn day7(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let operands: Vec<u64> = (0..rng.gen_range(2..=7))
+            .map(|_| rng.gen_range(1..100))
+            .collect();
+        let result = if rng.gen_bool(0.5) {
+            operands[1..]
+                .iter()
+                .fold(operands[0], |acc, &operand| match rng.gen_range(0..3) {
+                    0 => acc + operand,
+                    1 => acc * operand,
+                    _ => format!("{acc}{operand}").parse().unwrap(),
+                })
+        } else {
+            rng.gen_range(1..1_000_000)
+        };
+        writeln!(input, "{result}: {}", operands.iter().join(" ")).unwrap();
+    }
+    input
+}
+
+/// A map of antennas of a few frequencies.
+fn day8(rng: &mut StdRng, size: usize) -> String {
+    const FREQUENCIES: &[u8] = b"aA0zZ9";
+    map(rng, size, size, |rng| {
+        if rng.gen_bool(0.05) {
+            *FREQUENCIES.choose(rng).unwrap() as char
+        } else {
+            '.'
+        }
+    })
+}
+
+/// A disk map of `size` files with free space between them.
+fn day9(rng: &mut StdRng, size: usize) -> String {
+    let mut input = String::new();
+    for i in 0..size {
+        if i > 0 {
+            input.push(char::from_digit(rng.gen_range(0..10), 10).unwrap());
+        }
+        input.push(char::from_digit(rng.gen_range(1..10), 10).unwrap());
+    }
+    input.push('\n');
+    input
+}
+
+/// A topographic map of heights.
+fn day10(rng: &mut StdRng, size: usize) -> String {
+    map(rng, size, size, |rng| {
+        char::from_digit(rng.gen_range(0..10), 10).unwrap()
+    })
+}
+
+/// A row of engraved stones.
+fn day11(rng: &mut StdRng, size: usize) -> String {
+    let stones: Vec<u64> = (0..size).map(|_| rng.gen_range(0..10_000_000)).collect();
+    format!("{}\n", stones.iter().join(" "))
+}
+
+/// A garden of plots of a few plant types.
+fn day12(rng: &mut StdRng, size: usize) -> String {
+    map(rng, size, size, |rng| rng.gen_range('A'..='E'))
+}
+
+/// Claw machines, half of which can win the prize.
+fn day13(rng: &mut StdRng, size: usize) -> String {
+    let mut machines = Vec::new();
+    for _ in 0..size {
+        let a = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let b = (rng.gen_range(10..100), rng.gen_range(10..100));
+        let prize = if rng.gen_bool(0.5) {
+            let (presses_a, presses_b) = (rng.gen_range(1..=100), rng.gen_range(1..=100));
+            (
+                a.0 * presses_a + b.0 * presses_b,
+                a.1 * presses_a + b.1 * presses_b,
+            )
+        } else {
+            (rng.gen_range(1000..20000), rng.gen_range(1000..20000))
+        };
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+    machines.join("\n")
+}
+
+/// Robots in the 101x103 room of the real puzzle. They all stand on different tiles at some
+/// second, which is what part two looks for, so they are placed there and wound back.
+fn day14(rng: &mut StdRng, size: usize) -> String {
+    let (width, height): (i64, i64) = (101, 103);
+    let seconds = rng.gen_range(1..=width * height);
+    let tiles = (0..width).cartesian_product(0..height).collect_vec();
+    let mut input = String::new();
+    for &(x, y) in tiles.choose_multiple(rng, size) {
+        let (vx, vy) = (rng.gen_range(-99..100), rng.gen_range(-99..100));
+        let px = (x - seconds * vx).rem_euclid(width);
+        let py = (y - seconds * vy).rem_euclid(height);
+        writeln!(input, "p={px},{py} v={vx},{vy}").unwrap();
+    }
+    input
+}
+
+/// A walled warehouse with boxes and a robot, followed by the robot's moves.
+fn day15(rng: &mut StdRng, size: usize) -> String {
+    let side = size.max(3);
+    let mut warehouse = String::new();
+    for y in 0..side {
+        for x in 0..side {
+            warehouse.push(if x == 0 || y == 0 || x == side - 1 || y == side - 1 {
+                '#'
+            } else {
+                match rng.gen_range(0..10) {
+                    0 => '#',
+                    1..=2 => 'O',
+                    _ => '.',
+                }
+            });
+        }
+        warehouse.push('\n');
+    }
+    place(rng, &mut warehouse, '@', true);
+
+    warehouse.push('\n');
+    let moves: Vec<u8> = (0..10 * side)
+        .map(|_| *b"^v<>".choose(rng).unwrap())
+        .collect();
+    for line in moves.chunks(70) {
+        warehouse.push_str(std::str::from_utf8(line).unwrap());
+        warehouse.push('\n');
+    }
+    warehouse
+}
+
+/// A maze of `size` by `size` junctions (at least 2), with the start in the bottom left and the
+/// end in the top right like the real one. It's carved as a spanning tree with a few extra
+/// openings, so there can be several best paths.
+fn day16(rng: &mut StdRng, size: usize) -> String {
+    let side = 2 * size.max(2) + 1;
+    let mut walls = vec![vec![true; side]; side];
+    let mut stack = vec![(1, 1)];
+    walls[1][1] = false;
+    while let Some(&(x, y)) = stack.last() {
+        let unvisited: Vec<(usize, usize)> = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .iter()
+            .map(|&(dx, dy)| (x as isize + dx, y as isize + dy))
+            .filter(|&(nx, ny)| nx > 0 && ny > 0 && nx < side as isize && ny < side as isize)
+            .map(|(nx, ny)| (nx as usize, ny as usize))
+            .filter(|&(nx, ny)| walls[ny][nx])
+            .collect();
+        match unvisited.choose(rng) {
+            Some(&(nx, ny)) => {
+                walls[(y + ny) / 2][(x + nx) / 2] = false;
+                walls[ny][nx] = false;
+                stack.push((nx, ny));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    for _ in 0..size {
+        let (x, y) = (rng.gen_range(1..side - 1), rng.gen_range(1..side - 1));
+        if (x + y) % 2 == 1 {
+            walls[y][x] = false;
+        }
+    }
+
+    let mut maze = String::new();
+    for (y, row) in walls.iter().enumerate() {
+        for (x, &wall) in row.iter().enumerate() {
+            maze.push(match (x, y) {
+                _ if wall => '#',
+                (1, y) if y == side - 2 => 'S',
+                (x, 1) if x == side - 2 => 'E',
+                _ => '.',
+            });
+        }
+        maze.push('\n');
+    }
+    maze
+}
+
+/// A program shaped like the real ones: it sets B from the low 3 bits of A, mixes B and C with
+/// `size` instructions, outputs one register, and loops with `adv 3` and `jnz 0` until A is 0.
+/// Divisions only shift by B before it is mixed with larger values, so shifts stay below 8.
+///
+/// Programs are redrawn until some A makes them output themselves, so part two has an answer.
+/// That needs 3 bits of A per number, so at most 6 instructions do the mixing.
+fn day17(rng: &mut StdRng, size: usize) -> String {
+    let size = size.min(6);
+    loop {
+        let program = shift_loop(rng, size);
+        let raw: Vec<u8> = program
+            .iter()
+            .flat_map(|&(opcode, operand)| [opcode, operand])
+            .collect();
+        let memory = Memory { a: 0, b: 0, c: 0 };
+        if let Ok(Some(_)) = invert::lowest_a(memory, &raw, &raw) {
+            let a: u64 = rng.gen_range(1..1 << 30);
+            let program = raw.iter().join(",");
+            return format!(
+                "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {program}\n"
+            );
+        }
+    }
+}
+
+/// The opcodes and operands of a day 17 program, as described for [`day17`].
+fn shift_loop(rng: &mut StdRng, size: usize) -> Vec<(u8, u8)> {
+    let mixed_from = rng.gen_range(0..size);
+    let mut body = vec![(2, 4)];
+    for i in 0..size {
+        body.push(match (i.cmp(&mixed_from), rng.gen_range(0..4)) {
+            (Ordering::Less, 0) => (1, rng.gen_range(0..8)),
+            (Ordering::Less, 1) => (2, 4),
+            (Ordering::Less, 2) => (7, 5),
+            (Ordering::Less, _) => (7, rng.gen_range(0..4)),
+            (Ordering::Equal, 0..=1) => (4, rng.gen_range(0..8)),
+            (Ordering::Equal, _) => (6, rng.gen_range(0..4)),
+            (Ordering::Greater, 0..=1) => (1, rng.gen_range(0..8)),
+            (Ordering::Greater, _) => (4, rng.gen_range(0..8)),
+        });
+    }
+    body.push((5, rng.gen_range(4..7)));
+    body.insert(rng.gen_range(0..=body.len()), (0, 3));
+    body.push((3, 0));
+    body
+}
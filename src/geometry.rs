@@ -0,0 +1,231 @@
+use std::ops;
+
+/// A signed 2D vector. `y` grows downwards, so in a grid `y` is the row and `x` the column.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Coordinate {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Coordinate {
+    pub const ZERO: Coordinate = Coordinate { x: 0, y: 0 };
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Coordinate { x, y }
+    }
+
+    pub fn manhattan(self, other: Coordinate) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Coordinate> {
+        Direction::CARDINAL.into_iter().map(move |dir| self + dir)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Coordinate> {
+        Direction::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl ops::Add for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Coordinate {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl ops::Add<Direction> for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl ops::AddAssign for Coordinate {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::AddAssign<Direction> for Coordinate {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::Sub for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coordinate {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl ops::Sub<Direction> for Coordinate {
+    type Output = Coordinate;
+
+    fn sub(self, rhs: Direction) -> Self::Output {
+        self - rhs.offset()
+    }
+}
+
+impl ops::Neg for Coordinate {
+    type Output = Coordinate;
+
+    fn neg(self) -> Self::Output {
+        Coordinate {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl ops::Mul<isize> for Coordinate {
+    type Output = Coordinate;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Coordinate {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl ops::Div<isize> for Coordinate {
+    type Output = Coordinate;
+
+    fn div(self, rhs: isize) -> Self::Output {
+        Coordinate {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+/// Component-wise Euclidean remainder, for wrapping around a torus.
+impl ops::Rem for Coordinate {
+    type Output = Coordinate;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        Coordinate {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+        }
+    }
+}
+
+/// A compass direction, with north pointing up (towards negative `y`).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    pub fn offset(self) -> Coordinate {
+        match self {
+            Direction::North => Coordinate::new(0, -1),
+            Direction::NorthEast => Coordinate::new(1, -1),
+            Direction::East => Coordinate::new(1, 0),
+            Direction::SouthEast => Coordinate::new(1, 1),
+            Direction::South => Coordinate::new(0, 1),
+            Direction::SouthWest => Coordinate::new(-1, 1),
+            Direction::West => Coordinate::new(-1, 0),
+            Direction::NorthWest => Coordinate::new(-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Rotate 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// Rotate 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+impl From<Direction> for Coordinate {
+    fn from(dir: Direction) -> Coordinate {
+        dir.offset()
+    }
+}
+
+/// A position together with the direction it is facing.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Pose {
+    pub position: Coordinate,
+    pub direction: Direction,
+}
+
+impl Pose {
+    pub fn new(position: Coordinate, direction: Direction) -> Self {
+        Pose {
+            position,
+            direction,
+        }
+    }
+
+    /// The position one step ahead.
+    pub fn ahead(self) -> Coordinate {
+        self.position + self.direction
+    }
+
+    pub fn step(self) -> Pose {
+        Pose::new(self.ahead(), self.direction)
+    }
+
+    pub fn turn_left(self) -> Pose {
+        Pose::new(self.position, self.direction.turn_left())
+    }
+
+    pub fn turn_right(self) -> Pose {
+        Pose::new(self.position, self.direction.turn_right())
+    }
+
+    pub fn reverse(self) -> Pose {
+        Pose::new(self.position, self.direction.reverse())
+    }
+}
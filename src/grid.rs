@@ -0,0 +1,217 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::geometry::Coordinate;
+
+/// A rectangular grid stored in row-major order.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, f: impl FnMut(Coordinate) -> T) -> Self {
+        let cells = positions(width, height).map(f).collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse a character map, one row per line, ending at the first blank line. `cell` converts
+    /// each character, or returns what it expected instead.
+    pub fn parse_with(
+        input: &str,
+        cell: impl FnMut(Coordinate, char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        Grid::parse_section(input, input, cell)
+    }
+
+    /// Like [`Grid::parse_with`], but parsing `section`, a slice of `input` such as one of its
+    /// paragraphs.
+    pub fn parse_section(
+        input: &str,
+        section: &str,
+        mut cell: impl FnMut(Coordinate, char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in section.lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            let mut row_width = 0;
+            for (i, c) in line.char_indices() {
+                let pos = Coordinate::new(row_width as isize, height as isize);
+                let value = cell(pos, c).map_err(|expected| {
+                    ParseError::at(input, &line[i..i + c.len_utf8()], expected)
+                })?;
+                cells.push(value);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let expected = format!("a row of {width} cells");
+                    return Err(ParseError::at(input, line, expected));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        let width =
+            width.ok_or_else(|| ParseError::at(input, &section[section.len()..], "a grid"))?;
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Coordinate) -> bool {
+        (0..self.width as isize).contains(&pos.x) && (0..self.height as isize).contains(&pos.y)
+    }
+
+    fn offset_of(&self, pos: Coordinate) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: Coordinate) -> Option<&T> {
+        self.offset_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Coordinate) -> Option<&mut T> {
+        self.offset_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        pos.neighbours4().filter(|&pos| self.contains(pos))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        pos.neighbours8().filter(|&pos| self.contains(pos))
+    }
+
+    /// Positions from `start` (inclusive) repeatedly moving by `step` until leaving the grid.
+    pub fn ray(
+        &self,
+        start: Coordinate,
+        step: impl Into<Coordinate>,
+    ) -> impl Iterator<Item = Coordinate> + '_ {
+        let step = step.into();
+        std::iter::successors(Some(start), move |&pos| Some(pos + step))
+            .take_while(|&pos| self.contains(pos))
+    }
+
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> {
+        let (width, height) = (self.width, self.height);
+        assert!(
+            row < height,
+            "row {row} is outside the {width}x{height} grid"
+        );
+        self.cells[row * width..(row + 1) * width].iter()
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        // Also rules out a width of 0, which `step_by` can't take
+        let (width, height) = (self.width, self.height);
+        assert!(
+            col < width,
+            "column {col} is outside the {width}x{height} grid"
+        );
+        self.cells[col..].iter().step_by(width)
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Coordinate> {
+        positions(self.width, self.height)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find_all<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Coordinate> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(input, |_, c| Ok(c))
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Coordinate) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, pos: Coordinate) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the {width}x{height} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.height {
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn positions(width: usize, height: usize) -> impl Iterator<Item = Coordinate> {
+    (0..height as isize).flat_map(move |y| (0..width as isize).map(move |x| Coordinate::new(x, y)))
+}
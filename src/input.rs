@@ -0,0 +1,94 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing at a directory of `<day>.txt` inputs.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// The inputs directory from `AOC_INPUT_DIR`, falling back to the `inputs/` folder of this
+/// repository so the runner works from any working directory.
+pub fn default_dir() -> PathBuf {
+    match env::var_os(INPUT_DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+    }
+}
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    /// `<dir>/<day>.txt`
+    Dir(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// See [`default_dir`].
+    pub fn from_env() -> Self {
+        InputSource::Dir(default_dir())
+    }
+
+    /// A path argument, where `-` means stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Dir(dir) => Some(dir.join(format!("{day}.txt"))),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self.path(day) {
+            Some(path) => fs::read_to_string(&path).map_err(|error| {
+                io::Error::new(error.kind(), format!("{}: {error}", path.display()))
+            }),
+            None => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content)?;
+                Ok(content)
+            }
+        }
+    }
+}
+
+/// A puzzle input named `<day>.txt` or `<day>-<name>.txt`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputFile {
+    pub day: u8,
+    /// The file stem, e.g. `16` or `16-large`.
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Every input file in `dir`, sorted by day and name. Other files are ignored.
+pub fn input_files(dir: &Path) -> io::Result<Vec<InputFile>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)
+        .map_err(|error| io::Error::new(error.kind(), format!("{}: {error}", dir.display())))?
+    {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let day = name.split_once('-').map_or(name, |(day, _)| day);
+        if let Ok(day) = day.parse() {
+            let name = name.to_string();
+            files.push(InputFile { day, name, path });
+        }
+    }
+    files.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+    Ok(files)
+}
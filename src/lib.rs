@@ -0,0 +1,17 @@
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod error;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+pub mod report;
+pub mod runner;
+pub mod search;
+mod solution;
+
+pub use error::ParseError;
+pub use solution::Solution;
@@ -0,0 +1,79 @@
+//! Opt-in parallelism for solvers that work through independent sub-problems.
+//!
+//! Everything runs on the calling thread unless [`set_threads`] asks for more than one, so by
+//! default timings are for a single core and logs come out in order.
+
+use std::iter::Sum;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use log::debug;
+use rayon::prelude::*;
+use rayon::{ThreadPoolBuildError, ThreadPoolBuilder};
+
+static THREADS: AtomicUsize = AtomicUsize::new(1);
+
+/// Split the work in [`map`], [`sum`] and [`count`] across `threads` threads from now on, or
+/// across every core if `threads` is 0. Can only be called once.
+pub fn set_threads(threads: usize) -> Result<(), ThreadPoolBuildError> {
+    ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build_global()?;
+    THREADS.store(rayon::current_num_threads(), Ordering::Relaxed);
+    Ok(())
+}
+
+/// The number of threads solvers may use.
+pub fn threads() -> usize {
+    THREADS.load(Ordering::Relaxed)
+}
+
+/// Whether to split `items` items across threads, logging how many threads they go to.
+fn enabled(items: usize) -> bool {
+    let enabled = threads() > 1;
+    if enabled {
+        let threads = rayon::current_num_threads();
+        debug!(target: "parallel", "Splitting {items} items across {threads} threads");
+    }
+    enabled
+}
+
+/// `f` applied to each item, in order.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    if enabled(items.len()) {
+        items.par_iter().map(f).collect()
+    } else {
+        items.iter().map(f).collect()
+    }
+}
+
+/// The sum of `f` over the items.
+pub fn sum<T, S, F>(items: &[T], f: F) -> S
+where
+    T: Sync,
+    S: Sum + Send,
+    F: Fn(&T) -> S + Sync + Send,
+{
+    if enabled(items.len()) {
+        items.par_iter().map(f).sum()
+    } else {
+        items.iter().map(f).sum()
+    }
+}
+
+/// The number of items for which `f` is true.
+pub fn count<T, F>(items: &[T], f: F) -> usize
+where
+    T: Sync,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    if enabled(items.len()) {
+        items.par_iter().filter(|item| f(item)).count()
+    } else {
+        items.iter().filter(|item| f(item)).count()
+    }
+}
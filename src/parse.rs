@@ -0,0 +1,110 @@
+//! Parsers for the line formats that recur across puzzle inputs.
+//!
+//! Each takes the whole `input` alongside the `text` to parse, which must be a slice of `input`,
+//! so errors point at the right line and column.
+
+use std::str::FromStr;
+
+use crate::error::{parse_number, ParseError};
+use crate::geometry::Coordinate;
+use crate::grid::Grid;
+
+/// Whitespace-separated numbers, e.g. `3 4 -1`.
+pub fn numbers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace()
+        .map(|token| parse_number(input, token))
+        .collect()
+}
+
+/// Numbers separated by `separator`, with optional surrounding whitespace, e.g. `75,47,61`.
+pub fn numbers_separated_by<T: FromStr>(
+    input: &str,
+    text: &str,
+    separator: char,
+) -> Result<Vec<T>, ParseError> {
+    text.split(separator)
+        .map(|token| parse_number(input, token.trim()))
+        .collect()
+}
+
+/// `N` comma-separated numbers, each optionally labelled, e.g. `3,-3`, `p=0,4` or
+/// `X+94, Y+34`. A label is a run of letters, optionally followed by `=`, and a `+` or `-` after
+/// it is the number's sign.
+pub fn vector<T: FromStr, const N: usize>(input: &str, text: &str) -> Result<[T; N], ParseError> {
+    let mut components = text.split(',');
+    let mut values = Vec::with_capacity(N);
+    for _ in 0..N {
+        let component = components
+            .next()
+            .ok_or_else(|| ParseError::at(input, &text[text.len()..], "`,`"))?
+            .trim();
+        let number = component.trim_start_matches(char::is_alphabetic);
+        let number = number.strip_prefix('=').unwrap_or(number);
+        values.push(parse_number(input, number)?);
+    }
+    if let Some(extra) = components.next() {
+        return Err(ParseError::at(input, extra, "end of vector"));
+    }
+    Ok(values.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+/// A two-dimensional [`vector`] as a coordinate.
+pub fn coordinate(input: &str, text: &str) -> Result<Coordinate, ParseError> {
+    let [x, y] = vector(input, text)?;
+    Ok(Coordinate::new(x, y))
+}
+
+/// The values of the first `N` non-blank lines of `text`, which must be `key: value` headers
+/// with the given keys in order, e.g. `Register A: 729`.
+pub fn headers<'a, const N: usize>(
+    input: &str,
+    text: &'a str,
+    keys: [&str; N],
+) -> Result<[&'a str; N], ParseError> {
+    let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+    let mut values = Vec::with_capacity(N);
+    for key in keys {
+        let expected = format!("`{key}: ...`");
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::at(input, &text[text.len()..], expected.clone()))?;
+        let value = line
+            .strip_prefix(key)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .ok_or_else(|| ParseError::at(input, line, expected))?;
+        values.push(value.trim());
+    }
+    Ok(values.try_into().unwrap_or_else(|_| unreachable!()))
+}
+
+/// The blocks of lines in `text` separated by blank lines, without the blank lines.
+pub fn paragraphs(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        // Skip blank lines
+        while let Some(line) = rest.split_inclusive('\n').next() {
+            if !line.trim().is_empty() {
+                break;
+            }
+            rest = &rest[line.len()..];
+        }
+        if rest.is_empty() {
+            return None;
+        }
+        let mut end = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                break;
+            }
+            end += line.len();
+        }
+        let paragraph = rest[..end].trim_end();
+        rest = &rest[end..];
+        Some(paragraph)
+    })
+}
+
+/// A character map, one row per line, ending at the first blank line of `text`.
+pub fn char_grid(input: &str, text: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse_section(input, text, |_, c| Ok(c))
+}
@@ -0,0 +1,124 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::runner::{DayResult, Part};
+
+/// Output format of a run report.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    /// `Csv` for paths ending in `.csv`, otherwise `Json`.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("csv") => Format::Csv,
+            _ => Format::Json,
+        }
+    }
+}
+
+/// One part of one day in a run report. Fields that don't apply, e.g. the answer when parsing
+/// failed, are left empty.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: String,
+    /// Path of the input file, or `<stdin>`.
+    pub input: String,
+    pub input_sha256: Option<String>,
+    pub answer: Option<String>,
+    pub answer_type: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Lowercase hex SHA-256 of the input text.
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", Sha256::digest(input.as_bytes()))
+}
+
+impl Record {
+    /// One record per solved part of `result`.
+    pub fn solved(result: &DayResult, input_name: &str, input: &str) -> Vec<Self> {
+        let hash = input_hash(input);
+        result
+            .parts
+            .iter()
+            .map(|part| Record {
+                day: result.day,
+                part: part.part.to_string(),
+                input: input_name.to_string(),
+                input_sha256: Some(hash.clone()),
+                answer: Some(part.answer.clone()),
+                answer_type: Some(part.answer_type.to_string()),
+                parse_ns: Some(nanos(result.parse_time)),
+                solve_ns: Some(nanos(part.elapsed)),
+                error: None,
+            })
+            .collect()
+    }
+
+    /// One record per part in `parts` that couldn't be solved because of `error`. `input` is
+    /// `None` if it couldn't be read.
+    pub fn failed(
+        day: u8,
+        parts: &[Part],
+        input_name: &str,
+        input: Option<&str>,
+        error: &str,
+    ) -> Vec<Self> {
+        let hash = input.map(input_hash);
+        parts
+            .iter()
+            .map(|part| Record {
+                day,
+                part: part.to_string(),
+                input: input_name.to_string(),
+                input_sha256: hash.clone(),
+                answer: None,
+                answer_type: None,
+                parse_ns: None,
+                solve_ns: None,
+                error: Some(error.to_string()),
+            })
+            .collect()
+    }
+}
+
+/// Write `records` to `writer` as a JSON array or as CSV with a header row.
+pub fn write(writer: impl Write, format: Format, records: &[Record]) -> io::Result<()> {
+    match format {
+        Format::Json => {
+            let mut writer = writer;
+            serde_json::to_writer_pretty(&mut writer, records)?;
+            writeln!(writer)
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(writer);
+            for record in records {
+                writer.serialize(record)?;
+            }
+            writer.flush()
+        }
+    }
+}
+
+/// Write `records` to the file at `path`, in the format given by its extension.
+pub fn save(path: &Path, records: &[Record]) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write(&mut writer, Format::from_path(path), records)?;
+    writer.flush()
+}
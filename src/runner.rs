@@ -0,0 +1,136 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::days::*;
+use crate::error::ParseError;
+use crate::Solution;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("a"),
+            Part::Two => f.pad("b"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Part::One),
+            "b" => Ok(Part::Two),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: String,
+    /// Name of the answer's type, e.g. `usize`.
+    pub answer_type: Cow<'static, str>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Type-erased entry point for one day, so days can be looked up and run by number.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    pub day: u8,
+    run: fn(&str, &[Part]) -> Result<DayResult, ParseError>,
+}
+
+impl Solver {
+    pub const fn new<S: Solution>() -> Self {
+        Solver {
+            day: S::DAY,
+            run: run::<S>,
+        }
+    }
+
+    pub fn run(&self, input: &str, parts: &[Part]) -> Result<DayResult, ParseError> {
+        (self.run)(input, parts)
+    }
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver::new::<Day1>(),
+    Solver::new::<Day2>(),
+    Solver::new::<Day3>(),
+    Solver::new::<Day4>(),
+    Solver::new::<Day5>(),
+    Solver::new::<Day6>(),
+    Solver::new::<Day7>(),
+    Solver::new::<Day8>(),
+    Solver::new::<Day9>(),
+    Solver::new::<Day10>(),
+    Solver::new::<Day11>(),
+    Solver::new::<Day12>(),
+    Solver::new::<Day13>(),
+    Solver::new::<Day14>(),
+    Solver::new::<Day15>(),
+    Solver::new::<Day16>(),
+    Solver::new::<Day17>(),
+];
+
+pub fn solver(day: u8) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|solver| solver.day == day)
+}
+
+/// The last path segment of `T`'s name, e.g. `String` rather than `alloc::string::String`.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+fn run<S: Solution>(input: &str, parts: &[Part]) -> Result<DayResult, ParseError> {
+    let now = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = now.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let now = Instant::now();
+            let (answer, answer_type) = match part {
+                Part::One => (S::part_one(&parsed).to_string(), type_name::<S::PartOne>()),
+                Part::Two => (S::part_two(&parsed).to_string(), type_name::<S::PartTwo>()),
+            };
+            PartResult {
+                part,
+                answer,
+                answer_type: answer_type.into(),
+                elapsed: now.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(DayResult {
+        day: S::DAY,
+        parse_time,
+        parts,
+    })
+}
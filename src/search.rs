@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+/// The result of a shortest-path search: the cheapest cost to every explored node, and every
+/// predecessor through which that cost is achieved.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N> {
+    costs: HashMap<N, usize>,
+    predecessors: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+impl<N: Eq + Hash + Clone> ShortestPaths<N> {
+    /// The cost of the cheapest path to a goal, if one was reached.
+    pub fn cost(&self) -> Option<usize> {
+        self.goals.first().map(|goal| self.costs[goal])
+    }
+
+    /// Every goal node reached at the optimal cost.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The cheapest known cost to `node`.
+    pub fn cost_to(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    /// The nodes immediately before `node` on its cheapest paths. Together these form a DAG of all
+    /// optimal paths from the start.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One optimal path from the start to a goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        let mut path = vec![self.goals.first()?.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node that lies on at least one optimal path to a goal.
+    pub fn nodes_on_optimal_paths(&self) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut to_visit = self.goals.clone();
+        while let Some(node) = to_visit.pop() {
+            if nodes.insert(node.clone()) {
+                to_visit.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        nodes
+    }
+}
+
+struct Entry<N> {
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Entry<N> {
+    // Reversed so that `BinaryHeap` pops the lowest priority first
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's algorithm from `start` until every goal at the optimal cost has been found.
+/// `successors` yields each neighbour with the cost of the edge to it.
+pub fn dijkstra<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// A* search. `heuristic` must never overestimate the remaining cost and must be consistent,
+/// otherwise some optimal paths may be missed.
+pub fn astar<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> ShortestPaths<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut goals = Vec::new();
+    let mut best = None;
+    let mut queue = BinaryHeap::from([Entry {
+        priority: heuristic(&start),
+        cost: 0,
+        node: start,
+    }]);
+    while let Some(Entry {
+        priority,
+        cost,
+        node,
+    }) = queue.pop()
+    {
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+        if cost > costs[&node] {
+            // A cheaper route to this node was found after this entry was queued
+            continue;
+        }
+        if is_goal(&node) {
+            best = Some(cost);
+            goals.push(node);
+            continue;
+        }
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+            match costs.get(&next) {
+                Some(&known) if next_cost > known => continue,
+                Some(&known) if next_cost == known => {
+                    predecessors.entry(next).or_default().push(node.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            costs.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), vec![node.clone()]);
+            queue.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+    ShortestPaths {
+        costs,
+        predecessors,
+        goals,
+    }
+}
@@ -0,0 +1,18 @@
+use std::fmt::Display;
+
+use crate::error::ParseError;
+
+/// A solver for a single day: parse the input once, then answer each part from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+}
@@ -0,0 +1,52 @@
+//! Solvers that go over `run --timeout` or `--max-memory`, checked to be stopped and reported.
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+use advent_of_code_2024::generate::generate;
+
+fn run(args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .args(["--input", "-"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn timeout_stops_a_solver_that_never_halts() {
+    // `jnz 0` with A never reaching 0 loops forever
+    let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n";
+    let output = run(&["run", "17", "a", "--timeout", "0.2"], input);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Day 17 went over budget: it took longer than 200.00ms"));
+}
+
+#[test]
+fn max_memory_stops_a_solver_that_allocates_too_much() {
+    let input = generate(11, 5, 0).unwrap();
+    let output = run(&["run", "11", "--max-memory", "1"], &input);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Day 11 went over budget: it used more than 1 MiB"));
+}
+
+#[test]
+fn solvers_within_budget_give_their_answers() {
+    let input = generate(1, 10, 0).unwrap();
+    let isolated = run(&["run", "1", "-q", "--timeout", "10"], &input);
+    let direct = run(&["run", "1", "-q"], &input);
+    assert!(isolated.status.success());
+    assert_eq!(isolated.stdout, direct.stdout);
+}
@@ -0,0 +1,139 @@
+//! Naive and optimised solvers for the same puzzle, checked to agree on inputs from the
+//! generators in `advent_of_code_2024::generate`.
+//!
+//! A disagreement is shrunk to a minimal input and saved to `tests/counterexamples/` as
+//! `<day>-<hash>.txt`, where `saved_counterexamples_agree` keeps checking it.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestError, TestRunner};
+
+use advent_of_code_2024::days::*;
+use advent_of_code_2024::generate::generate;
+use advent_of_code_2024::report::input_hash;
+use advent_of_code_2024::Solution;
+
+/// Blinks to simulate on day 11; the naive solver can't go much further.
+const BLINKS: u8 = 25;
+
+/// Checks that the solvers for a day agree on an input, describing how they differ if not.
+type Property = fn(&str) -> Result<(), String>;
+
+fn parse<S: Solution>(input: &str) -> Result<S::Input, String> {
+    S::parse(input).map_err(|error| error.to_string())
+}
+
+fn day2_agrees(input: &str) -> Result<(), String> {
+    for levels in parse::<Day2>(input)? {
+        let naive = day2::b::is_safe_dampened_brute_force(&levels);
+        let fast = day2::b::is_safe_dampened(&levels);
+        if naive != fast {
+            return Err(format!("{levels:?}: brute force {naive}, dampener {fast}"));
+        }
+    }
+    Ok(())
+}
+
+fn day11_agrees(input: &str) -> Result<(), String> {
+    let stones = parse::<Day11>(input)?;
+    let naive = day11::a::count(&stones, BLINKS);
+    let memoised = day11::b::count(&stones, BLINKS);
+    if naive != memoised {
+        return Err(format!(
+            "{stones:?}: simulated {naive}, memoised {memoised}"
+        ));
+    }
+    Ok(())
+}
+
+fn day13_agrees(input: &str) -> Result<(), String> {
+    for machine in parse::<Day13>(input)? {
+        let naive = day13::a::solve_machine(machine);
+        let closed_form = day13::b::presses(machine);
+        let agrees = match closed_form {
+            // The brute force only tries up to 100 presses of each button
+            Some((a, b)) if a <= 100 && b <= 100 => naive == Some(a as usize * 3 + b as usize),
+            Some((a, b)) => naive.is_none_or(|naive| naive >= a as usize * 3 + b as usize),
+            None => naive.is_none(),
+        };
+        if !agrees {
+            return Err(format!(
+                "{machine:?}: brute force {naive:?}, closed form presses {closed_form:?}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn property(day: u8) -> Option<Property> {
+    match day {
+        2 => Some(day2_agrees),
+        11 => Some(day11_agrees),
+        13 => Some(day13_agrees),
+        _ => None,
+    }
+}
+
+fn counterexamples() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/counterexamples")
+}
+
+/// Check the day's property on generated inputs of up to `max_size`, saving the shrunk input if
+/// it fails.
+fn check(day: u8, max_size: usize) {
+    let agrees = property(day).unwrap();
+    let strategy = (1..=max_size, any::<u64>())
+        .prop_map(move |(size, seed)| generate(day, size, seed).unwrap());
+    let mut runner = TestRunner::new(Config {
+        failure_persistence: None,
+        ..Config::default()
+    });
+    match runner.run(&strategy, |input| {
+        agrees(&input).map_err(TestCaseError::fail)
+    }) {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, input)) => {
+            let path = counterexamples().join(format!("{day}-{}.txt", &input_hash(&input)[..8]));
+            fs::create_dir_all(counterexamples()).unwrap();
+            fs::write(&path, &input).unwrap();
+            panic!("{reason}\nSaved the input to {}", path.display());
+        }
+        Err(error) => panic!("{error}"),
+    }
+}
+
+#[test]
+fn day2_dampener_matches_brute_force() {
+    check(2, 20);
+}
+
+#[test]
+fn day11_memoised_matches_simulation() {
+    check(11, 3);
+}
+
+#[test]
+fn day13_closed_form_matches_brute_force() {
+    check(13, 10);
+}
+
+#[test]
+fn saved_counterexamples_agree() {
+    let Ok(entries) = fs::read_dir(counterexamples()) else {
+        return;
+    };
+    for entry in entries {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_string_lossy();
+        let day = name.split('-').next().and_then(|day| day.parse().ok());
+        let agrees = day
+            .and_then(property)
+            .unwrap_or_else(|| panic!("{}: not a counterexample for a known day", path.display()));
+        let input = fs::read_to_string(&path).unwrap();
+        if let Err(reason) = agrees(&input) {
+            panic!("{}: {reason}", path.display());
+        }
+    }
+}
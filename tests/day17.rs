@@ -0,0 +1,396 @@
+//! The day 17 computer, driven directly rather than through the solver.
+
+use advent_of_code_2024::days::day17::analysis::{analyse, Block, Edge, Shape, Termination};
+use advent_of_code_2024::days::day17::asm::{assemble, disassemble, encode};
+use advent_of_code_2024::days::day17::debugger::{Breakpoint, Debugger, Stop};
+use advent_of_code_2024::days::day17::invert::{lowest_a, Unsupported};
+use advent_of_code_2024::days::day17::machine::{
+    DecodeError, Halt, Instruction, Operand, Register,
+};
+use advent_of_code_2024::days::day17::{Machine, Memory, Operation};
+use advent_of_code_2024::days::Day17;
+use advent_of_code_2024::Solution;
+use itertools::Itertools;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+fn load(a: isize, b: isize, c: isize, program: &str) -> <Day17 as Solution>::Input {
+    let input =
+        format!("Register A: {a}\nRegister B: {b}\nRegister C: {c}\n\nProgram: {program}\n");
+    Day17::parse(&input).unwrap()
+}
+
+#[test]
+fn small_examples() {
+    let (memory, program, _) = load(0, 0, 9, "2,6");
+    let mut machine = Machine::new(memory);
+    assert_eq!(machine.run_until_halt(&program), (vec![], Halt::End));
+    assert_eq!(machine.memory.b, 1);
+
+    let (memory, program, _) = load(10, 0, 0, "5,0,5,1,5,4");
+    assert_eq!(Machine::new(memory).run(&program), [0, 1, 2]);
+
+    let (memory, program, _) = load(2024, 0, 0, "0,1,5,4,3,0");
+    let mut machine = Machine::new(memory);
+    let outputs = machine.run(&program);
+    assert_eq!(outputs, [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+    assert_eq!(machine.memory.a, 0);
+
+    let (memory, program, _) = load(0, 29, 0, "1,7");
+    let mut machine = Machine::new(memory);
+    machine.run(&program);
+    assert_eq!(machine.memory.b, 26);
+
+    let (memory, program, _) = load(0, 2024, 43690, "4,0");
+    let mut machine = Machine::new(memory);
+    machine.run(&program);
+    assert_eq!(machine.memory.b, 44354);
+}
+
+#[test]
+fn step_reports_each_instruction() {
+    let (memory, program, _) = load(729, 0, 0, "0,1,5,4,3,0");
+    let mut machine = Machine::new(memory);
+
+    let step = machine.step(&program).unwrap();
+    assert_eq!(step.pc, 0);
+    assert_eq!(step.operation.instruction, Instruction::DivA);
+    assert_eq!(step.operand, 1);
+    assert_eq!(step.before.a, 729);
+    assert_eq!(step.after.a, 364);
+    assert_eq!(step.output, None);
+
+    let step = machine.step(&program).unwrap();
+    assert_eq!(step.operation.operand, Operand::Register(Register::A));
+    assert_eq!(step.operand, 364);
+    assert_eq!(step.output, Some(4));
+
+    let step = machine.step(&program).unwrap();
+    assert_eq!((step.pc, machine.pc), (4, 0));
+    assert_eq!(machine.executed, 3);
+}
+
+#[test]
+fn trace_ends_with_the_halting_reason() {
+    let (memory, program, _) = load(729, 0, 0, "0,1,5,4,3,0");
+    let (steps, halt) = Machine::new(memory).trace(&program);
+    assert_eq!(halt, Halt::End);
+    let outputs: Vec<u8> = steps.iter().filter_map(|step| step.output).collect();
+    assert_eq!(outputs, [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+    assert!(steps.windows(2).all(|pair| pair[0].after == pair[1].before));
+}
+
+#[test]
+fn instruction_limit_stops_endless_loops() {
+    let (memory, program, _) = load(1, 0, 0, "3,0");
+    let mut machine = Machine::new(memory).with_limit(100);
+    assert_eq!(machine.run_until_halt(&program), (vec![], Halt::Limit));
+    assert_eq!(machine.executed, 100);
+}
+
+#[test]
+fn odd_jumps_read_operands_as_opcodes() {
+    // Jumps to `out A` at 3, then reads 2 with no operand after it
+    let (memory, program, _) = load(13, 0, 0, "3,3,0,5,4,2");
+    assert_eq!(
+        Machine::new(memory).run_until_halt(&program),
+        (vec![5], Halt::End)
+    );
+
+    // Jumps to `out` with the reserved combo operand 7
+    let (memory, program, _) = load(1, 0, 0, "3,3,0,5,7,0");
+    assert_eq!(
+        Machine::new(memory).run_until_halt(&program),
+        (vec![], Halt::Invalid(3, DecodeError::Operand(7)))
+    );
+}
+
+#[test]
+fn disassembly_labels_jump_targets() {
+    let (_, _, operations) = load(0, 0, 0, "2,4,1,5,7,5,4,3,0,3,5,5,3,0");
+    let text = disassemble(&operations);
+    assert_eq!(
+        text,
+        "L0:\n    bst A\n    bxl 5\n    cdv B\n    bxc 3\n    adv 3\n    out B\n    jnz L0\n"
+    );
+    assert_eq!(assemble(&text).unwrap(), operations);
+}
+
+#[test]
+fn assembly_accepts_comments_forward_labels_and_addresses() {
+    let text =
+        "; skip the output while A is odd\nstart: adv 1\n  jnz 6 ; forwards\n  out A\nend:\n";
+    let operations = assemble(text).unwrap();
+    assert_eq!(encode(&operations), [0, 1, 3, 6, 5, 4]);
+    let text = "jnz end\nout 0\nout 1\nend: out 2\n";
+    assert_eq!(encode(&assemble(text).unwrap()), [3, 6, 5, 0, 5, 1, 5, 2]);
+}
+
+#[test]
+fn assembly_errors_point_at_the_problem() {
+    let error = assemble("adv 3\nmul 2\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    let error = assemble("out 4\n").unwrap_err();
+    assert_eq!(error.expected, "a combo operand: `A`, `B`, `C` or 0 to 3");
+    let error = assemble("bxl A\n").unwrap_err();
+    assert_eq!(error.expected, "a literal operand from 0 to 7");
+    let error = assemble("jnz far\nout 0\nout 0\nout 0\nfar: out 0\n").unwrap_err();
+    assert_eq!(
+        error.expected,
+        "a label jnz can reach, not one at address 8"
+    );
+    let error = assemble("adv 1 2\n").unwrap_err();
+    assert_eq!(error.found, "`2`");
+}
+
+#[test]
+fn debugger_stops_at_breakpoints_and_watchpoints() {
+    let (memory, program, _) = load(729, 0, 0, "0,1,5,4,3,0");
+    let mut debugger = Debugger::new(memory, program);
+    debugger.add_breakpoint(Breakpoint::Instruction(Instruction::Output));
+    assert_eq!(
+        debugger.continue_running(),
+        Stop::Breakpoint(Breakpoint::Instruction(Instruction::Output))
+    );
+    assert_eq!((debugger.machine().pc, debugger.outputs()), (2, &[][..]));
+    // Continuing runs the instruction at the breakpoint before stopping at it again
+    debugger.continue_running();
+    assert_eq!(debugger.outputs(), [4]);
+
+    debugger.execute("delete out").unwrap();
+    debugger.execute("watch A").unwrap();
+    assert_eq!(
+        debugger.continue_running(),
+        Stop::Watchpoint {
+            register: Register::A,
+            before: 182,
+            after: 91
+        }
+    );
+    debugger.execute("delete A").unwrap();
+    assert_eq!(debugger.continue_running(), Stop::Halt(Halt::End));
+    assert_eq!(debugger.execute("output").unwrap(), "4,6,3,5,6,3,5,2,1,0");
+}
+
+#[test]
+fn debugger_reverse_steps_through_history() {
+    let (memory, program, _) = load(729, 0, 0, "0,1,5,4,3,0");
+    let mut debugger = Debugger::new(memory, program);
+    debugger.execute("step 5").unwrap();
+    assert_eq!(debugger.outputs(), [4, 6]);
+    let after_five = debugger.machine().clone();
+
+    debugger.execute("step 2").unwrap();
+    debugger.execute("rs 2").unwrap();
+    assert_eq!(debugger.machine(), &after_five);
+    debugger.execute("rs 3").unwrap();
+    assert_eq!(debugger.outputs(), [4]);
+    assert_eq!(debugger.machine().memory.a, 364);
+    assert_eq!(
+        debugger.execute("reverse-step 5").unwrap(),
+        "No more history\n  0: adv 1            A=729 B=0 C=0"
+    );
+}
+
+#[test]
+fn debugger_edits_registers() {
+    let (memory, program, _) = load(729, 0, 0, "0,1,5,4,3,0");
+    let mut debugger = Debugger::new(memory, program);
+    assert_eq!(
+        debugger.execute("set A 16").unwrap(),
+        "  0: adv 1            A=16 B=0 C=0"
+    );
+    debugger.execute("continue").unwrap();
+    assert_eq!(debugger.outputs(), [0, 4, 2, 1, 0]);
+    debugger.execute("set pc 4").unwrap();
+    assert_eq!(debugger.machine().pc, 4);
+    assert_eq!(
+        debugger.execute("set pc 3").unwrap(),
+        "  3: bxc 3            A=0 B=0 C=0"
+    );
+    assert!(debugger.execute("set pc -1").is_err());
+    assert!(debugger.execute("set D 1").is_err());
+    assert!(debugger.execute("frobnicate").is_err());
+}
+
+#[test]
+fn debugger_reverse_steps_through_edits() {
+    let (memory, program, _) = load(729, 0, 0, "0,1,5,4,3,0");
+    let mut debugger = Debugger::new(memory, program);
+    debugger.execute("set A 16").unwrap();
+    debugger.execute("step").unwrap();
+    debugger.execute("set pc 0").unwrap();
+
+    // Each edit and step is undone on its own
+    debugger.execute("rs").unwrap();
+    assert_eq!((debugger.machine().pc, debugger.machine().memory.a), (2, 8));
+    debugger.execute("rs").unwrap();
+    assert_eq!(
+        (debugger.machine().pc, debugger.machine().memory.a),
+        (0, 16)
+    );
+    assert_eq!(
+        debugger.execute("rs").unwrap(),
+        "  0: adv 1            A=729 B=0 C=0"
+    );
+}
+
+#[test]
+fn inverting_finds_the_lowest_a() {
+    let (memory, program, _) = load(2024, 0, 0, "0,3,5,4,3,0");
+    let quine = [0, 3, 5, 4, 3, 0];
+    assert_eq!(lowest_a(memory, &program, &quine), Ok(Some(117440)));
+
+    // Outputs the octal digits of A, lowest first
+    let (memory, program, _) = load(0, 0, 0, "5,4,0,3,3,0");
+    assert_eq!(lowest_a(memory, &program, &[1, 1]), Ok(Some(9)));
+    assert_eq!(lowest_a(memory, &program, &[]), Ok(None));
+
+    // Halts only when A is 0 and the output depends on a shift by B
+    let (memory, program, _) = load(0, 0, 0, "2,4,1,1,7,5,4,4,5,5,0,1,3,0");
+    let target = Machine::new(Memory { a: 1234, ..memory }).run(&program);
+    let a = lowest_a(memory, &program, &target).unwrap().unwrap();
+    assert!(a <= 1234);
+    assert_eq!(Machine::new(Memory { a, ..memory }).run(&program), target);
+
+    // A shift by A itself, which is 0 once A has a bit from 64 up
+    let (memory, program, _) = load(65536, 0, 0, "6,4,0,5,5,0,0,1,3,0");
+    let target = Machine::new(memory).run(&program);
+    let a = lowest_a(memory, &program, &target).unwrap().unwrap();
+    assert!(a <= 65536);
+    assert_eq!(Machine::new(Memory { a, ..memory }).run(&program), target);
+
+    // 0 is the lowest
+    let (memory, program, _) = load(0, 0, 0, "5,4");
+    assert_eq!(lowest_a(memory, &program, &[0]), Ok(Some(0)));
+
+    // Outputs A from `out A` at the odd address 3
+    let (memory, program, _) = load(0, 0, 0, "3,3,0,5,4,2");
+    assert_eq!(lowest_a(memory, &program, &[5]), Ok(Some(5)));
+}
+
+#[test]
+fn inverting_reports_unsupported_programs() {
+    // Any A but 0 loops forever without changing, which the search notices
+    let (memory, program, _) = load(0, 0, 0, "3,0");
+    assert_eq!(lowest_a(memory, &program, &[1]), Ok(None));
+    // Any A but 0 outputs 0 forever, so a long enough run of them takes every step to rule out
+    let (memory, program, _) = load(0, 0, 0, "5,5,3,0");
+    assert_eq!(
+        lowest_a(memory, &program, &[0; 100_000]),
+        Err(Unsupported::StepLimit(1_000_000))
+    );
+    let (memory, program, _) = load(0, -1, 0, "5,5");
+    assert_eq!(
+        lowest_a(memory, &program, &[7]),
+        Err(Unsupported::NegativeRegister(Register::B))
+    );
+}
+
+#[test]
+fn analysis_finds_the_shift_loop() {
+    let (_, _, operations) = load(0, 0, 0, "2,4,1,1,7,5,4,4,1,4,0,3,5,5,3,0");
+    let analysis = analyse(&operations);
+    assert_eq!(
+        analysis.blocks,
+        [Block {
+            start: 0,
+            end: 16,
+            successors: vec![Edge::Block(0), Edge::End],
+        }]
+    );
+    assert_eq!(analysis.outputs.len(), 1);
+    assert_eq!(analysis.outputs[0].address, 12);
+    assert_eq!(analysis.outputs[0].registers, [Register::A]);
+    assert_eq!(analysis.shape, Shape::ShiftLoop { bits: 3 });
+    assert_eq!(analysis.termination, Termination::Halts);
+}
+
+#[test]
+fn analysis_flags_other_shapes() {
+    // B and C are read before being set, so they feed the output
+    let (_, _, operations) = load(0, 0, 0, "4,0,5,5,3,0");
+    let analysis = analyse(&operations);
+    assert_eq!(analysis.outputs[0].registers, [Register::B, Register::C]);
+    assert_eq!(analysis.shape, Shape::Other);
+    assert_eq!(analysis.termination, Termination::Loops(0));
+
+    // A shift by a register may be 0
+    let (_, _, operations) = load(0, 0, 0, "0,5,5,4,3,0");
+    let analysis = analyse(&operations);
+    assert_eq!(analysis.shape, Shape::Other);
+    assert_eq!(analysis.termination, Termination::Unknown);
+
+    let (_, _, operations) = load(0, 0, 0, "0,1,3,6,5,1,3,1");
+    let analysis = analyse(&operations);
+    assert_eq!(
+        analysis.blocks,
+        [
+            Block {
+                start: 0,
+                end: 4,
+                successors: vec![Edge::Block(6), Edge::Block(4)],
+            },
+            Block {
+                start: 4,
+                end: 6,
+                successors: vec![Edge::Block(6)],
+            },
+            Block {
+                start: 6,
+                end: 8,
+                successors: vec![Edge::Misaligned(1), Edge::End],
+            },
+        ]
+    );
+    assert_eq!(analysis.outputs[0].registers, []);
+    // The jump to 1 runs code the blocks don't describe
+    assert_eq!(analysis.termination, Termination::Unknown);
+}
+
+proptest! {
+    #[test]
+    fn inverting_matches_the_emulator(
+        body in vec(
+            (0..8u8, 0..8u8).prop_filter("7 is a reserved combo operand", |&(opcode, operand)| {
+                Operation::decode(opcode, operand).is_ok()
+            }),
+            0..8,
+        ),
+        shift in 1..4u8,
+        a in prop_oneof![0..1isize << 12, 0..=isize::MAX],
+    ) {
+        // Any body, jumps and all, in a loop that shifts A down. Jumps can keep it from halting,
+        // in which case the search may give up, but a program that halts has an answer no
+        // higher than the A it ran with
+        let mut program: Vec<u8> = body.iter().flat_map(|&(opcode, operand)| [opcode, operand]).collect();
+        program.extend([0, shift, 3, 0]);
+        let program = program.iter().join(",");
+        let (memory, program, _) = load(a, 0, 0, &program);
+        let (target, halt) = Machine::new(memory).with_limit(10_000).run_until_halt(&program);
+        match lowest_a(memory, &program, &target) {
+            Ok(Some(lowest)) => {
+                let run = Machine::new(Memory { a: lowest, ..memory }).with_limit(1_000_000).run_until_halt(&program);
+                prop_assert_eq!(run, (target, Halt::End));
+                prop_assert!(halt != Halt::End || lowest <= a);
+            }
+            Ok(None) => prop_assert_ne!(halt, Halt::End),
+            Err(unsupported) => {
+                prop_assert_ne!(halt, Halt::End);
+                prop_assert_eq!(unsupported, Unsupported::StepLimit(1_000_000));
+            }
+        }
+    }
+
+    #[test]
+    fn assembly_round_trips(program in vec((0..8u8, 0..7u8), 0..12)) {
+        let operations: Vec<Operation> = program
+            .iter()
+            .map(|&(opcode, operand)| Operation::decode(opcode, operand).unwrap())
+            .collect();
+        let text = disassemble(&operations);
+        prop_assert_eq!(assemble(&text).unwrap(), operations.clone());
+        let raw: Vec<u8> = program.iter().flat_map(|&(opcode, operand)| [opcode, operand]).collect();
+        prop_assert_eq!(encode(&operations), raw);
+    }
+}
@@ -0,0 +1,155 @@
+//! The example inputs from each day's puzzle statement, checked against the example answers.
+
+use std::fs;
+use std::path::Path;
+
+use advent_of_code_2024::days::*;
+use advent_of_code_2024::geometry::Coordinate;
+use advent_of_code_2024::Solution;
+
+fn example<S: Solution>(name: &str) -> S::Input {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/examples")
+        .join(name);
+    let content =
+        fs::read_to_string(&path).unwrap_or_else(|error| panic!("{}: {error}", path.display()));
+    S::parse(&content).unwrap_or_else(|error| panic!("{}", error.with_file(&path)))
+}
+
+#[test]
+fn day1() {
+    let input = example::<Day1>("1.txt");
+    assert_eq!(Day1::part_one(&input), 11);
+    assert_eq!(Day1::part_two(&input), 31);
+}
+
+#[test]
+fn day2() {
+    let input = example::<Day2>("2.txt");
+    assert_eq!(Day2::part_one(&input), 2);
+    assert_eq!(Day2::part_two(&input), 4);
+}
+
+#[test]
+fn day3() {
+    assert_eq!(Day3::part_one(&example::<Day3>("3.txt")), 161);
+    assert_eq!(Day3::part_two(&example::<Day3>("3b.txt")), 48);
+}
+
+#[test]
+fn day4() {
+    let input = example::<Day4>("4.txt");
+    assert_eq!(Day4::part_one(&input), 18);
+    assert_eq!(Day4::part_two(&input), 9);
+}
+
+#[test]
+fn day5() {
+    let input = example::<Day5>("5.txt");
+    assert_eq!(Day5::part_one(&input), 143);
+    assert_eq!(Day5::part_two(&input), 123);
+}
+
+#[test]
+fn day6() {
+    let input = example::<Day6>("6.txt");
+    assert_eq!(Day6::part_one(&input), 41);
+    assert_eq!(Day6::part_two(&input), 6);
+}
+
+#[test]
+fn day7() {
+    let input = example::<Day7>("7.txt");
+    assert_eq!(Day7::part_one(&input), 3749);
+    assert_eq!(Day7::part_two(&input), 11387);
+}
+
+#[test]
+fn day8() {
+    let input = example::<Day8>("8.txt");
+    assert_eq!(Day8::part_one(&input), 14);
+    assert_eq!(Day8::part_two(&input), 34);
+}
+
+#[test]
+fn day9() {
+    let input = example::<Day9>("9.txt");
+    assert_eq!(Day9::part_one(&input), 1928);
+    assert_eq!(Day9::part_two(&input), 2858);
+}
+
+#[test]
+fn day10() {
+    let input = example::<Day10>("10.txt");
+    assert_eq!(Day10::part_one(&input), 36);
+    assert_eq!(Day10::part_two(&input), 81);
+}
+
+#[test]
+fn day11() {
+    let input = example::<Day11>("11.txt");
+    assert_eq!(Day11::part_one(&input), 55312);
+    // Not given in the puzzle statement
+    assert_eq!(Day11::part_two(&input), 65601038650482);
+}
+
+#[test]
+fn day12() {
+    let input = example::<Day12>("12.txt");
+    assert_eq!(Day12::part_one(&input), 1930);
+    assert_eq!(Day12::part_two(&input), 1206);
+}
+
+#[test]
+fn day13() {
+    let input = example::<Day13>("13.txt");
+    assert_eq!(Day13::part_one(&input), 480);
+    // Not given in the puzzle statement
+    assert_eq!(Day13::part_two(&input), 875318608908);
+}
+
+#[test]
+fn day14() {
+    // Part two has no example
+    let input = example::<Day14>("14.txt");
+    assert_eq!(Day14::safety_factor(&input, Coordinate::new(11, 7)), 12);
+}
+
+#[test]
+fn day15() {
+    assert_eq!(Day15::part_one(&example::<Day15>("15.txt")), 2028);
+    let input = example::<Day15>("15-large.txt");
+    assert_eq!(Day15::part_one(&input), 10092);
+    assert_eq!(Day15::part_two(&input), 9021);
+
+    // Maps need no wall around them; boxes stop at the edge
+    let input = Day15::parse("#...\n.@O.\n....\n\n>>>>\n").unwrap();
+    assert_eq!(Day15::part_one(&input), 103);
+    assert_eq!(Day15::part_two(&input), 106);
+}
+
+#[test]
+fn day16() {
+    let input = example::<Day16>("16.txt");
+    assert_eq!(Day16::part_one(&input), 7036);
+    assert_eq!(Day16::part_two(&input), 45);
+
+    let input = example::<Day16>("16-large.txt");
+    assert_eq!(Day16::part_one(&input), 11048);
+    assert_eq!(Day16::part_two(&input), 64);
+
+    // Mazes need no wall around them, but the end must be reachable
+    let input = Day16::parse("S.\n#E\n").unwrap();
+    assert_eq!(Day16::part_one(&input), 1002);
+    let error = Day16::parse("S#E\n").unwrap_err();
+    assert_eq!(error.expected, "an end `E` reachable from the start");
+}
+
+#[test]
+fn day17() {
+    assert_eq!(
+        Day17::part_one(&example::<Day17>("17.txt")),
+        "4,6,3,5,6,3,5,2,1,0"
+    );
+    assert_eq!(Day17::part_two(&example::<Day17>("17b.txt")), 117440);
+}
@@ -0,0 +1,22 @@
+//! Random inputs from the generators, checked to parse and solve without panicking.
+
+use advent_of_code_2024::generate::generate;
+use advent_of_code_2024::runner::{Part, SOLVERS};
+
+#[test]
+fn generated_inputs_solve() {
+    for solver in SOLVERS {
+        for size in [1, 5, 30] {
+            for seed in 0..10 {
+                let input = generate(solver.day, size, seed)
+                    .unwrap_or_else(|| panic!("no generator for day {}", solver.day));
+                if let Err(error) = solver.run(&input, &Part::BOTH) {
+                    panic!(
+                        "day {} size {size} seed {seed}: {error}\n{input}",
+                        solver.day
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! The solvers that split their work across threads, checked to give the same answers as when
+//! they run serially.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use advent_of_code_2024::generate::generate;
+use advent_of_code_2024::parallel;
+use advent_of_code_2024::runner::{self, Part};
+
+const DAYS: [u8; 4] = [6, 7, 12, 13];
+
+fn answers(inputs: &[(u8, String)]) -> Vec<String> {
+    inputs
+        .iter()
+        .flat_map(|(day, input)| {
+            let result = runner::solver(*day).unwrap().run(input, &Part::BOTH);
+            result.unwrap().parts.into_iter().map(|part| part.answer)
+        })
+        .collect()
+}
+
+// Threads can only be started once per process, so this is the only test in the file that
+// starts them in its own process
+#[test]
+fn parallel_answers_match_serial() {
+    let inputs: Vec<(u8, String)> = DAYS
+        .into_iter()
+        .flat_map(|day| (0..5).map(move |seed| (day, generate(day, 20, seed).unwrap())))
+        .collect();
+    let serial = answers(&inputs);
+    parallel::set_threads(4).unwrap();
+    assert_eq!(parallel::threads(), 4);
+    assert_eq!(answers(&inputs), serial);
+}
+
+#[test]
+fn jobs_leave_solvers_the_threads_asked_for() {
+    let input = generate(7, 20, 0).unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "7", "--input", "-", "--jobs", "2", "--threads", "4"])
+        .env("RUST_LOG", "parallel=debug")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("across 4 threads"), "{stderr}");
+}